@@ -6,9 +6,11 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
-use alloc::string::String;
-use casper_contract::{contract_api::runtime, ext_ffi};
-use casper_types::{api_error, runtime_args, ApiError, ContractHash, Key, RuntimeArgs, URef};
+use alloc::{string::String, vec::Vec};
+use casper_contract::{contract_api::runtime, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    api_error, bytesrepr::Bytes, runtime_args, ApiError, ContractHash, Key, RuntimeArgs, URef,
+};
 
 const ENTRY_POINT_MINT: &str = "mint";
 const ENTRY_POINT_REGISTER_OWNER: &str = "register_owner";
@@ -16,6 +18,8 @@ const ENTRY_POINT_REGISTER_OWNER: &str = "register_owner";
 const ARG_NFT_CONTRACT_HASH: &str = "nft_contract_hash";
 const ARG_TOKEN_OWNER: &str = "token_owner";
 const ARG_TOKEN_META_DATA: &str = "token_meta_data";
+const ARG_TOKEN_META_DATA_BYTES: &str = "token_meta_data_bytes";
+const ARG_TOKEN_METADATA_KINDS: &str = "token_metadata_kinds";
 const ARG_TOKEN_HASH: &str = "token_hash";
 
 #[no_mangle]
@@ -43,15 +47,32 @@ pub extern "C" fn call() {
     );
     runtime::put_key(&register_name, package_uref.into());
 
+    let mut mint_runtime_args = runtime_args! {
+        ARG_TOKEN_HASH => token_hash,
+        ARG_TOKEN_OWNER => token_owner,
+        ARG_TOKEN_META_DATA => token_metadata,
+    };
+    // Binary metadata is only forwarded when passed, as the contract treats it as optional.
+    if get_named_arg_size(ARG_TOKEN_META_DATA_BYTES).is_some() {
+        let token_metadata_bytes = runtime::get_named_arg::<Bytes>(ARG_TOKEN_META_DATA_BYTES);
+        mint_runtime_args
+            .insert(ARG_TOKEN_META_DATA_BYTES, token_metadata_bytes)
+            .unwrap_or_revert();
+    }
+    // The metadata kinds of the token are only forwarded when selected, as an empty selection
+    // differs from none.
+    if get_named_arg_size(ARG_TOKEN_METADATA_KINDS).is_some() {
+        let token_metadata_kinds = runtime::get_named_arg::<Vec<u8>>(ARG_TOKEN_METADATA_KINDS);
+        mint_runtime_args
+            .insert(ARG_TOKEN_METADATA_KINDS, token_metadata_kinds)
+            .unwrap_or_revert();
+    }
+
     let (receipt_name, owned_tokens_dictionary_key, _token_id_string) =
         runtime::call_contract::<(String, Key, String)>(
             nft_contract_hash,
             ENTRY_POINT_MINT,
-            runtime_args! {
-                ARG_TOKEN_HASH => token_hash,
-                ARG_TOKEN_OWNER => token_owner,
-                ARG_TOKEN_META_DATA => token_metadata,
-            },
+            mint_runtime_args,
         );
 
     runtime::put_key(&receipt_name, owned_tokens_dictionary_key);
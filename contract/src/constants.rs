@@ -10,35 +10,71 @@ pub const ARG_ACL_PACKAGE_MODE: &str = "acl_package_mode";
 pub const ARG_ACL_WHITELIST: &str = "acl_whitelist";
 pub const ARG_ADDITIONAL_REQUIRED_METADATA: &str = "additional_required_metadata";
 pub const ARG_ALLOW_MINTING: &str = "allow_minting";
+pub const ARG_ALLOWED_URI_SCHEMES: &str = "allowed_uri_schemes";
 pub const ARG_APPROVE_ALL: &str = "approve_all";
+pub const ARG_BASE_URI: &str = "base_uri";
+pub const ARG_BINARY_METADATA_LAYOUT: &str = "binary_metadata_layout";
 pub const ARG_BURN_MODE: &str = "burn_mode";
+pub const ARG_BURN_STORAGE_MODE: &str = "burn_storage_mode";
+pub const ARG_CLAWBACK_MODE: &str = "clawback_mode";
+pub const ARG_COLLECTION_DESCRIPTION: &str = "collection_description";
+pub const ARG_COLLECTION_EXTERNAL_LINK: &str = "collection_external_link";
+pub const ARG_COLLECTION_IMAGE: &str = "collection_image";
 pub const ARG_COLLECTION_NAME: &str = "collection_name";
 pub const ARG_COLLECTION_SYMBOL: &str = "collection_symbol";
+pub const ARG_CONTRACT_URI: &str = "contract_uri";
 pub const ARG_CONTRACT_WHITELIST: &str = "contract_whitelist";
+pub const ARG_DEADLINE: &str = "deadline";
 pub const ARG_EVENTS_MODE: &str = "events_mode";
+pub const ARG_HASH_IDENTIFIER_MODE: &str = "hash_identifier_mode";
 pub const ARG_HASH_KEY_NAME_1_0_0: &str = "hash_key_name";
 pub const ARG_HOLDER_MODE: &str = "holder_mode";
+pub const ARG_IDENTIFIER_CHARSET: &str = "identifier_charset";
+pub const ARG_IDENTIFIER_MAX_LENGTH: &str = "identifier_max_length";
+pub const ARG_IDENTIFIER_MIN_LENGTH: &str = "identifier_min_length";
 pub const ARG_IDENTIFIER_MODE: &str = "identifier_mode";
+pub const ARG_IDENTIFIER_PREFIX: &str = "identifier_prefix";
+pub const ARG_ISSUER: &str = "issuer";
 pub const ARG_JSON_SCHEMA: &str = "json_schema";
+pub const ARG_LOCKED_METADATA_FIELDS: &str = "locked_metadata_fields";
+pub const ARG_MAX_METADATA_SIZE: &str = "max_metadata_size";
+pub const ARG_MEMO: &str = "memo";
+pub const ARG_METADATA_EDITOR: &str = "metadata_editor";
 pub const ARG_METADATA_MUTABILITY: &str = "metadata_mutability";
+pub const ARG_METADATA_STORAGE_FORMAT: &str = "metadata_storage_format";
+pub const ARG_METADATA_UPDATE_MODE: &str = "metadata_update_mode";
 pub const ARG_MINTING_MODE: &str = "minting_mode";
 pub const ARG_NAMED_KEY_CONVENTION: &str = "named_key_convention";
 pub const ARG_NFT_KIND: &str = "nft_kind";
 pub const ARG_NFT_METADATA_KIND: &str = "nft_metadata_kind";
 pub const ARG_NFT_PACKAGE_KEY: &str = "cep78_package_key";
+pub const ARG_NONCE: &str = "nonce";
 pub const ARG_OPTIONAL_METADATA: &str = "optional_metadata";
+pub const ARG_ORDINAL_ASSIGNMENT_MODE: &str = "ordinal_assignment_mode";
 pub const ARG_OPERATOR: &str = "operator";
 pub const ARG_OPERATOR_BURN_MODE: &str = "operator_burn_mode";
 pub const ARG_OWNERSHIP_MODE: &str = "ownership_mode";
 pub const ARG_OWNER_LOOKUP_MODE: &str = "owner_reverse_lookup_mode";
 pub const ARG_PACKAGE_OPERATOR_MODE: &str = "package_operator_mode";
+pub const ARG_PAGE_SIZE: &str = "page_size";
+pub const ARG_PAGE_START: &str = "page_start";
+pub const ARG_PLACEHOLDER_URI: &str = "placeholder_uri";
+pub const ARG_PROVENANCE_HASH: &str = "provenance_hash";
+pub const ARG_PUBLIC_KEY: &str = "public_key";
 pub const ARG_RECEIPT_NAME: &str = "receipt_name";
+pub const ARG_REVEAL_MODE: &str = "reveal_mode";
+pub const ARG_REVISION: &str = "revision";
+pub const ARG_SHUFFLE_SEED: &str = "shuffle_seed";
+pub const ARG_SIGNATURE: &str = "signature";
 pub const ARG_SOURCE_KEY: &str = "source_key";
 pub const ARG_SPENDER: &str = "spender";
+pub const ARG_STRICT_METADATA_VALIDATION: &str = "strict_metadata_validation";
 pub const ARG_TARGET_KEY: &str = "target_key";
 pub const ARG_TOKEN_HASH: &str = "token_hash";
 pub const ARG_TOKEN_ID: &str = "token_id";
 pub const ARG_TOKEN_META_DATA: &str = "token_meta_data";
+pub const ARG_TOKEN_META_DATA_BYTES: &str = "token_meta_data_bytes";
+pub const ARG_TOKEN_METADATA_KINDS: &str = "token_metadata_kinds";
 pub const ARG_TOKEN_OWNER: &str = "token_owner";
 pub const ARG_TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const ARG_TRANSFER_FILTER_CONTRACT: &str = "transfer_filter_contract";
@@ -47,53 +83,104 @@ pub const ARG_WHITELIST_MODE: &str = "whitelist_mode";
 pub const ENTRY_POINT_APPROVE: &str = "approve";
 pub const ENTRY_POINT_BALANCE_OF: &str = "balance_of";
 pub const ENTRY_POINT_BURN: &str = "burn";
+pub const ENTRY_POINT_CIRCULATING_SUPPLY: &str = "circulating_supply";
+pub const ENTRY_POINT_COLLECTION_METADATA: &str = "collection_metadata";
+pub const ENTRY_POINT_FORCE_BURN: &str = "force_burn";
+pub const ENTRY_POINT_FORCE_TRANSFER: &str = "force_transfer";
+pub const ENTRY_POINT_FREEZE_METADATA: &str = "freeze_metadata";
 pub const ENTRY_POINT_GET_APPROVED: &str = "get_approved";
 pub const ENTRY_POINT_INIT: &str = "init";
 pub const ENTRY_POINT_IS_APPROVED_FOR_ALL: &str = "is_approved_for_all";
+pub const ENTRY_POINT_ISSUER_OF: &str = "issuer_of";
 pub const ENTRY_POINT_METADATA: &str = "metadata";
+pub const ENTRY_POINT_METADATA_AT: &str = "metadata_at";
+pub const ENTRY_POINT_METADATA_BYTES: &str = "metadata_bytes";
+pub const ENTRY_POINT_METADATA_REVISION_COUNT: &str = "metadata_revision_count";
 pub const ENTRY_POINT_MIGRATE: &str = "migrate";
 pub const ENTRY_POINT_MINT: &str = "mint";
+pub const ENTRY_POINT_NUMBER_OF_BURNT_TOKENS: &str = "number_of_burnt_tokens";
+pub const ENTRY_POINT_NUMBER_OF_HOLDERS: &str = "number_of_holders";
+pub const ENTRY_POINT_NUMBER_OF_MINTED_TOKENS: &str = "number_of_minted_tokens";
 pub const ENTRY_POINT_OWNER_OF: &str = "owner_of";
+pub const ENTRY_POINT_REVEAL: &str = "reveal";
 pub const ENTRY_POINT_REVOKE: &str = "revoke";
 pub const ENTRY_POINT_REGISTER_OWNER: &str = "register_owner";
 pub const ENTRY_POINT_SET_APPROVALL_FOR_ALL: &str = "set_approval_for_all";
+pub const ENTRY_POINT_SET_JSON_SCHEMA: &str = "set_json_schema";
 pub const ENTRY_POINT_SET_TOKEN_METADATA: &str = "set_token_metadata";
 pub const ENTRY_POINT_SET_VARIABLES: &str = "set_variables";
+pub const ENTRY_POINT_TOKEN_HASH_OF: &str = "token_hash_of";
+pub const ENTRY_POINT_TOKEN_INDEX_OF: &str = "token_index_of";
+pub const ENTRY_POINT_TOKEN_INFO: &str = "token_info";
+pub const ENTRY_POINT_TOKEN_METADATA_KINDS: &str = "token_metadata_kinds";
+pub const ENTRY_POINT_TOKENS_ISSUED_BY: &str = "tokens_issued_by";
+pub const ENTRY_POINT_TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const ENTRY_POINT_TRANSFER: &str = "transfer";
+pub const ENTRY_POINT_TRANSFER_BY_SIGNATURE: &str = "transfer_by_signature";
 pub const ENTRY_POINT_UPDATED_RECEIPTS: &str = "updated_receipts";
 
 pub const ACL_PACKAGE_MODE: &str = "acl_package_mode";
 pub const ACL_WHITELIST: &str = "acl_whitelist";
 pub const ALLOW_MINTING: &str = "allow_minting";
+pub const ALLOWED_URI_SCHEMES: &str = "allowed_uri_schemes";
 pub const APPROVED: &str = "approved";
+pub const BASE_URI: &str = "base_uri";
+pub const BINARY_METADATA_LAYOUT: &str = "binary_metadata_layout";
 pub const BURN_MODE: &str = "burn_mode";
+pub const BURN_STORAGE_MODE: &str = "burn_storage_mode";
 pub const BURNT_TOKENS: &str = "burnt_tokens";
+pub const CLAWBACK_MODE: &str = "clawback_mode";
+pub const COLLECTION_METADATA: &str = "collection_metadata";
 pub const COLLECTION_NAME: &str = "collection_name";
 pub const COLLECTION_SYMBOL: &str = "collection_symbol";
 pub const CONTRACT_WHITELIST: &str = "contract_whitelist";
 pub const EVENT_TYPE: &str = "event_type";
 pub const EVENTS: &str = "events";
 pub const EVENTS_MODE: &str = "events_mode";
+pub const FROZEN_METADATA: &str = "frozen_metadata";
 pub const HASH_BY_INDEX: &str = "hash_by_index";
+pub const HASH_IDENTIFIER_MODE: &str = "hash_identifier_mode";
 pub const HOLDER_MODE: &str = "holder_mode";
+pub const IDENTIFIER_CHARSET: &str = "identifier_charset";
+pub const IDENTIFIER_MAX_LENGTH: &str = "identifier_max_length";
+pub const IDENTIFIER_MIN_LENGTH: &str = "identifier_min_length";
 pub const IDENTIFIER_MODE: &str = "identifier_mode";
+pub const IDENTIFIER_PREFIX: &str = "identifier_prefix";
 pub const INDEX_BY_HASH: &str = "index_by_hash";
 pub const INSTALLER: &str = "installer";
+pub const ISSUED_TOKENS: &str = "issued_tokens";
+pub const ISSUED_TOKEN_RECORDS: &str = "issued_token_records";
+pub const ISSUED_TOKEN_COUNTS: &str = "issued_token_counts";
 pub const JSON_SCHEMA: &str = "json_schema";
+pub const JSON_SCHEMA_VERSION: &str = "json_schema_version";
+pub const JSON_SCHEMAS: &str = "json_schemas";
+pub const LOCKED_METADATA_FIELDS: &str = "locked_metadata_fields";
+pub const MAX_METADATA_SIZE: &str = "max_metadata_size";
+pub const METADATA_BINARY: &str = "metadata_binary";
 pub const METADATA_CEP78: &str = "metadata_cep78";
 pub const METADATA_CUSTOM_VALIDATED: &str = "metadata_custom_validated";
+pub const METADATA_EDITOR: &str = "metadata_editor";
+pub const METADATA_HISTORY: &str = "metadata_history";
 pub const METADATA_MUTABILITY: &str = "metadata_mutability";
 pub const METADATA_NFT721: &str = "metadata_nft721";
 pub const METADATA_RAW: &str = "metadata_raw";
+pub const METADATA_REVISION_COUNTS: &str = "metadata_revision_counts";
+pub const METADATA_STORAGE_FORMAT: &str = "metadata_storage_format";
+pub const METADATA_UPDATE_MODE: &str = "metadata_update_mode";
 pub const MIGRATION_FLAG: &str = "migration_flag";
 pub const MINTING_MODE: &str = "minting_mode";
 pub const NFT_KIND: &str = "nft_kind";
+pub const MEMO: &str = "memo";
 pub const NFT_METADATA_KIND: &str = "nft_metadata_kind";
 pub const NFT_METADATA_KINDS: &str = "nft_metadata_kinds";
+pub const NONCES: &str = "nonces";
+pub const NUMBER_OF_BURNT_TOKENS: &str = "number_of_burnt_tokens";
+pub const NUMBER_OF_HOLDERS: &str = "number_of_holders";
 pub const NUMBER_OF_MINTED_TOKENS: &str = "number_of_minted_tokens";
 pub const OPERATOR: &str = "operator";
 pub const OPERATORS: &str = "operators";
 pub const OPERATOR_BURN_MODE: &str = "operator_burn_mode";
+pub const ORDINAL_ASSIGNMENT_MODE: &str = "ordinal_assignment_mode";
 pub const OWNED_TOKENS: &str = "owned_tokens";
 pub const OWNER: &str = "owner";
 pub const BURNER: &str = "burner";
@@ -101,16 +188,26 @@ pub const OWNERSHIP_MODE: &str = "ownership_mode";
 pub const PACKAGE_OPERATOR_MODE: &str = "package_operator_mode";
 pub const PAGE_LIMIT: &str = "page_limit";
 pub const PAGE_TABLE: &str = "page_table";
+pub const PLACEHOLDER_URI: &str = "placeholder_uri";
+pub const PROVENANCE_HASH: &str = "provenance_hash";
 pub const RECEIPT_NAME: &str = "receipt_name";
 pub const RECIPIENT: &str = "recipient";
+pub const REMINTED_TOKENS: &str = "reminted_tokens";
 pub const REPORTING_MODE: &str = "reporting_mode";
+pub const REVEAL_MODE: &str = "reveal_mode";
+pub const REVEALED: &str = "revealed";
 pub const RLO_MFLAG: &str = "rlo_mflag";
 pub const SENDER: &str = "sender";
+pub const SHUFFLE_SEED: &str = "shuffle_seed";
+pub const SHUFFLED_INDICES: &str = "shuffled_indices";
 pub const SPENDER: &str = "spender";
+pub const STRICT_METADATA_VALIDATION: &str = "strict_metadata_validation";
 pub const TOKEN_COUNT: &str = "balances";
 pub const TOKEN_ID: &str = "token_id";
 pub const TOKEN_ISSUERS: &str = "token_issuers";
+pub const TOKEN_METADATA_KINDS: &str = "token_metadata_kinds";
 pub const TOKEN_OWNERS: &str = "token_owners";
+pub const TOKEN_SCHEMA_VERSIONS: &str = "token_schema_versions";
 pub const TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const TRANSFER_FILTER_CONTRACT: &str = "transfer_filter_contract";
 pub const TRANSFER_FILTER_CONTRACT_METHOD: &str = "can_transfer";
@@ -120,5 +217,25 @@ pub const WHITELIST_MODE: &str = "whitelist_mode";
 // The cap on the amount of tokens within a given CEP-78 collection.
 pub const MAX_TOTAL_TOKEN_SUPPLY: u64 = 1_000_000u64;
 
+// The token uri schemes accepted under strict metadata validation unless the installer provides
+// its own allowlist.
+pub const DEFAULT_ALLOWED_URI_SCHEMES: [&str; 3] = ["ipfs://", "ar://", "https://"];
+
+// The characters custom identifiers may use unless the installer provides its own charset.
+pub const DEFAULT_IDENTIFIER_CHARSET: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// The cap on the length of custom identifiers, the length of the base16 encoded blake2b hashes
+// identifying tokens by default. It keeps the dictionary item keys derived from identifiers, such
+// as the keys of metadata revisions, within the dictionary item key length limit.
+pub const MAX_CUSTOM_IDENTIFIER_LENGTH: u32 = 64;
+
+// The cap on the number of tokens returned by a single call to tokens_issued_by.
+pub const MAX_ISSUED_TOKENS_PAGE_SIZE: u64 = 100;
+
+// The cap on the length of the memo attached to a mint, transfer or burn, which is recorded in
+// every event emitted for it.
+pub const MAX_MEMO_LENGTH: usize = 256;
+
 pub const ACCESS_KEY_NAME_1_0_0: &str = "nft_contract_package_access";
 pub const HASH_KEY_NAME_1_0_0: &str = "nft_contract_package";
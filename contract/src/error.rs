@@ -173,6 +173,83 @@ pub enum NFTCoreError {
     MissingOperatorBurnMode = 168,
     InvalidIdentifier = 169,
     DuplicateIdentifier = 170,
+    MissingSignature = 171,
+    InvalidSignature = 172,
+    MissingPublicKey = 173,
+    InvalidPublicKey = 174,
+    MissingNonce = 175,
+    InvalidNonce = 176,
+    MissingDeadline = 177,
+    InvalidDeadline = 178,
+    ExpiredTransferAuthorization = 179,
+    MissingJsonSchemaVersion = 180,
+    InvalidJsonSchemaVersion = 181,
+    LockedMetadataField = 182,
+    MissingLockedMetadataFields = 183,
+    InvalidLockedMetadataFields = 184,
+    FrozenMetadata = 185,
+    MissingMetadataUpdateMode = 186,
+    InvalidMetadataUpdateMode = 187,
+    MissingMetadataEditor = 188,
+    InvalidMetadataEditor = 189,
+    MissingRevision = 190,
+    InvalidRevision = 191,
+    MissingMetadataRevision = 192,
+    MissingCollectionMetadata = 193,
+    InvalidCollectionMetadata = 194,
+    MissingBaseUri = 195,
+    InvalidBaseUri = 196,
+    InvalidChecksum = 197,
+    DisallowedTokenUriScheme = 198,
+    MissingStrictMetadataValidation = 199,
+    InvalidStrictMetadataValidation = 200,
+    MissingAllowedUriSchemes = 201,
+    InvalidAllowedUriSchemes = 202,
+    MetadataTooLarge = 203,
+    MissingMaxMetadataSize = 204,
+    InvalidMaxMetadataSize = 205,
+    MissingMetadataStorageFormat = 206,
+    InvalidMetadataStorageFormat = 207,
+    MissingBinaryMetadataLayout = 208,
+    InvalidBinaryMetadataLayout = 209,
+    InvalidBinaryMetadata = 210,
+    UnsupportedTokenMetadataKind = 211,
+    InvalidTokenMetadataKinds = 212,
+    MissingRevealMode = 213,
+    InvalidRevealMode = 214,
+    MissingProvenanceHash = 215,
+    InvalidProvenanceHash = 216,
+    MissingPlaceholderUri = 217,
+    InvalidPlaceholderUri = 218,
+    MissingRevealed = 219,
+    InvalidRevealed = 220,
+    AlreadyRevealed = 221,
+    ProvenanceHashMismatch = 222,
+    BaseUriLocked = 223,
+    MissingOrdinalAssignmentMode = 224,
+    InvalidOrdinalAssignmentMode = 225,
+    MissingShuffleSeed = 226,
+    InvalidShuffleSeed = 227,
+    CannotUpgradeShuffledSupply = 228,
+    MissingHashIdentifierMode = 229,
+    InvalidHashIdentifierMode = 230,
+    MissingIdentifierRules = 231,
+    InvalidIdentifierRules = 232,
+    InvalidCustomIdentifier = 233,
+    MissingNumberOfBurntTokens = 234,
+    InvalidNumberOfBurntTokens = 235,
+    MissingNumberOfHolders = 236,
+    InvalidNumberOfHolders = 237,
+    MissingIssuer = 238,
+    InvalidIssuer = 239,
+    MissingIssuedTokensPage = 240,
+    InvalidIssuedTokensPage = 241,
+    MissingBurnStorageMode = 242,
+    InvalidBurnStorageMode = 243,
+    MissingClawbackMode = 244,
+    InvalidClawbackMode = 245,
+    InvalidMemo = 246,
+    UnavailableSupplyStatistics = 247,
 }
 
 impl From<NFTCoreError> for ApiError {
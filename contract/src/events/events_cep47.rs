@@ -12,8 +12,8 @@ use casper_types::Key;
 
 use crate::{
     constants::{
-        BURNER, EVENTS, EVENT_TYPE, OPERATOR, OWNER, PREFIX_CEP78, PREFIX_HASH_KEY_NAME, RECIPIENT,
-        SENDER, SPENDER, TOKEN_ID,
+        BURNER, EVENTS, EVENT_TYPE, MEMO, OPERATOR, OWNER, PREFIX_CEP78, PREFIX_HASH_KEY_NAME,
+        RECIPIENT, SENDER, SPENDER, TOKEN_ID,
     },
     error::NFTCoreError,
     modalities::TokenIdentifier,
@@ -24,11 +24,18 @@ pub enum CEP47Event {
     Mint {
         recipient: Key,
         token_id: TokenIdentifier,
+        memo: Option<String>,
     },
     Burn {
         owner: Key,
         token_id: TokenIdentifier,
         burner: Key,
+        memo: Option<String>,
+    },
+    ForcedBurn {
+        owner: Key,
+        token_id: TokenIdentifier,
+        burner: Key,
     },
     ApprovalGranted {
         owner: Key,
@@ -51,11 +58,23 @@ pub enum CEP47Event {
         sender: Key,
         recipient: Key,
         token_id: TokenIdentifier,
+        memo: Option<String>,
+    },
+    ForcedTransfer {
+        owner: Key,
+        sender: Key,
+        recipient: Key,
+        token_id: TokenIdentifier,
     },
     MetadataUpdate {
         token_id: TokenIdentifier,
     },
+    MetadataFrozen {
+        token_id: TokenIdentifier,
+    },
     VariablesSet,
+    CollectionMetadataUpdate,
+    Revealed,
     Migrate,
 }
 
@@ -76,18 +95,23 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
         CEP47Event::Mint {
             recipient,
             token_id,
+            memo,
         } => {
             let mut event = BTreeMap::new();
             event.insert(PREFIX_HASH_KEY_NAME, package);
             event.insert(EVENT_TYPE, "Mint".to_string());
             event.insert(RECIPIENT, recipient.to_string());
             event.insert(TOKEN_ID, token_id.to_string());
+            if let Some(memo) = memo {
+                event.insert(MEMO, memo);
+            }
             event
         }
         CEP47Event::Burn {
             owner,
             token_id,
             burner,
+            memo,
         } => {
             let mut event = BTreeMap::new();
             event.insert(PREFIX_HASH_KEY_NAME, package);
@@ -95,6 +119,22 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(OWNER, owner.to_string());
             event.insert(TOKEN_ID, token_id.to_string());
             event.insert(BURNER, burner.to_string());
+            if let Some(memo) = memo {
+                event.insert(MEMO, memo);
+            }
+            event
+        }
+        CEP47Event::ForcedBurn {
+            owner,
+            token_id,
+            burner,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "ForcedBurn".to_string());
+            event.insert(OWNER, owner.to_string());
+            event.insert(TOKEN_ID, token_id.to_string());
+            event.insert(BURNER, burner.to_string());
             event
         }
         CEP47Event::ApprovalGranted {
@@ -138,6 +178,7 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             sender,
             recipient,
             token_id,
+            memo,
         } => {
             let mut event = BTreeMap::new();
             event.insert(PREFIX_HASH_KEY_NAME, package);
@@ -145,6 +186,24 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(SENDER, sender.to_string());
             event.insert(RECIPIENT, recipient.to_string());
             event.insert(TOKEN_ID, token_id.to_string());
+            if let Some(memo) = memo {
+                event.insert(MEMO, memo);
+            }
+            event
+        }
+        CEP47Event::ForcedTransfer {
+            owner,
+            sender,
+            recipient,
+            token_id,
+        } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "ForcedTransfer".to_string());
+            event.insert(OWNER, owner.to_string());
+            event.insert(SENDER, sender.to_string());
+            event.insert(RECIPIENT, recipient.to_string());
+            event.insert(TOKEN_ID, token_id.to_string());
             event
         }
         CEP47Event::MetadataUpdate { token_id } => {
@@ -154,6 +213,13 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(TOKEN_ID, token_id.to_string());
             event
         }
+        CEP47Event::MetadataFrozen { token_id } => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "MetadataFrozen".to_string());
+            event.insert(TOKEN_ID, token_id.to_string());
+            event
+        }
         CEP47Event::Migrate => {
             let mut event = BTreeMap::new();
             event.insert(PREFIX_HASH_KEY_NAME, package);
@@ -166,6 +232,18 @@ pub fn record_cep47_event_dictionary(event: CEP47Event) {
             event.insert(EVENT_TYPE, "VariablesSet".to_string());
             event
         }
+        CEP47Event::CollectionMetadataUpdate => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "CollectionMetadataUpdate".to_string());
+            event
+        }
+        CEP47Event::Revealed => {
+            let mut event = BTreeMap::new();
+            event.insert(PREFIX_HASH_KEY_NAME, package);
+            event.insert(EVENT_TYPE, "Revealed".to_string());
+            event
+        }
     };
     let dictionary_uref = match runtime::get_key(EVENTS) {
         Some(dict_uref) => dict_uref.into_uref().unwrap_or_revert(),
@@ -10,14 +10,21 @@ pub struct Mint {
     recipient: Key,
     token_id: String,
     data: String,
+    memo: Option<String>,
 }
 
 impl Mint {
-    pub fn new(recipient: Key, token_id: TokenIdentifier, data: String) -> Self {
+    pub fn new(
+        recipient: Key,
+        token_id: TokenIdentifier,
+        data: String,
+        memo: Option<String>,
+    ) -> Self {
         Self {
             recipient,
             token_id: token_id.to_string(),
             data,
+            memo,
         }
     }
 }
@@ -27,9 +34,28 @@ pub struct Burn {
     owner: Key,
     token_id: String,
     burner: Key,
+    memo: Option<String>,
 }
 
 impl Burn {
+    pub fn new(owner: Key, token_id: TokenIdentifier, burner: Key, memo: Option<String>) -> Self {
+        Self {
+            owner,
+            token_id: token_id.to_string(),
+            burner,
+            memo,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ForcedBurn {
+    owner: Key,
+    token_id: String,
+    burner: Key,
+}
+
+impl ForcedBurn {
     pub fn new(owner: Key, token_id: TokenIdentifier, burner: Key) -> Self {
         Self {
             owner,
@@ -101,6 +127,7 @@ pub struct Transfer {
     spender: Option<Key>,
     recipient: Key,
     token_id: String,
+    memo: Option<String>,
 }
 
 impl Transfer {
@@ -109,12 +136,33 @@ impl Transfer {
         spender: Option<Key>,
         recipient: Key,
         token_id: TokenIdentifier,
+        memo: Option<String>,
     ) -> Self {
         Self {
             owner,
             spender,
             recipient,
             token_id: token_id.to_string(),
+            memo,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ForcedTransfer {
+    owner: Key,
+    sender: Key,
+    recipient: Key,
+    token_id: String,
+}
+
+impl ForcedTransfer {
+    pub fn new(owner: Key, sender: Key, recipient: Key, token_id: TokenIdentifier) -> Self {
+        Self {
+            owner,
+            sender,
+            recipient,
+            token_id: token_id.to_string(),
         }
     }
 }
@@ -134,6 +182,19 @@ impl MetadataUpdated {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MetadataFrozen {
+    token_id: String,
+}
+
+impl MetadataFrozen {
+    pub fn new(token_id: TokenIdentifier) -> Self {
+        Self {
+            token_id: token_id.to_string(),
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq, Default)]
 pub struct VariablesSet {}
 
@@ -143,6 +204,45 @@ impl VariablesSet {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct CollectionMetadataUpdated {
+    contract_uri: String,
+    description: String,
+    image: String,
+    external_link: String,
+}
+
+impl CollectionMetadataUpdated {
+    pub fn new(
+        contract_uri: String,
+        description: String,
+        image: String,
+        external_link: String,
+    ) -> Self {
+        Self {
+            contract_uri,
+            description,
+            image,
+            external_link,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Revealed {
+    base_uri: String,
+    provenance_hash: String,
+}
+
+impl Revealed {
+    pub fn new(base_uri: String, provenance_hash: String) -> Self {
+        Self {
+            base_uri,
+            provenance_hash,
+        }
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq, Default)]
 pub struct Migration {}
 
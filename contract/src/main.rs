@@ -29,34 +29,62 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    contracts::NamedKeys, runtime_args, CLType, CLValue, ContractHash, ContractPackageHash,
-    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, KeyTag, Parameter, RuntimeArgs,
-    Tagged,
+    bytesrepr::{self, Bytes},
+    contracts::NamedKeys,
+    crypto::{self, Signature},
+    runtime_args, CLType, CLValue, ContractHash, ContractPackageHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, KeyTag, Parameter, PublicKey, RuntimeArgs, Tagged,
 };
 use constants::{
-    ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ACL_WHITELIST, ALLOW_MINTING, APPROVED,
-    ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST,
-    ARG_ADDITIONAL_REQUIRED_METADATA, ARG_ALLOW_MINTING, ARG_APPROVE_ALL, ARG_BURN_MODE,
-    ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST, ARG_EVENTS_MODE,
-    ARG_HASH_KEY_NAME_1_0_0, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA,
-    ARG_METADATA_MUTABILITY, ARG_MINTING_MODE, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND,
-    ARG_NFT_METADATA_KIND, ARG_NFT_PACKAGE_KEY, ARG_OPERATOR, ARG_OPERATOR_BURN_MODE,
-    ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_OPERATOR_MODE,
-    ARG_RECEIPT_NAME, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-    ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT,
-    ARG_WHITELIST_MODE, BURNT_TOKENS, BURN_MODE, COLLECTION_NAME, COLLECTION_SYMBOL,
-    ENTRY_POINT_APPROVE, ENTRY_POINT_BALANCE_OF, ENTRY_POINT_BURN, ENTRY_POINT_GET_APPROVED,
-    ENTRY_POINT_INIT, ENTRY_POINT_IS_APPROVED_FOR_ALL, ENTRY_POINT_METADATA, ENTRY_POINT_MIGRATE,
-    ENTRY_POINT_MINT, ENTRY_POINT_OWNER_OF, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE,
-    ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_SET_TOKEN_METADATA, ENTRY_POINT_SET_VARIABLES,
-    ENTRY_POINT_TRANSFER, ENTRY_POINT_UPDATED_RECEIPTS, EVENTS_MODE, HASH_BY_INDEX,
-    HASH_KEY_NAME_1_0_0, HOLDER_MODE, IDENTIFIER_MODE, INDEX_BY_HASH, INSTALLER, JSON_SCHEMA,
-    MAX_TOTAL_TOKEN_SUPPLY, METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_MUTABILITY,
-    METADATA_NFT721, METADATA_RAW, MINTING_MODE, NFT_KIND, NFT_METADATA_KIND, NFT_METADATA_KINDS,
-    NUMBER_OF_MINTED_TOKENS, OPERATOR, OPERATORS, OPERATOR_BURN_MODE, OWNED_TOKENS, OWNERSHIP_MODE,
-    PACKAGE_OPERATOR_MODE, PAGE_LIMIT, PAGE_TABLE, PREFIX_ACCESS_KEY_NAME, PREFIX_CEP78,
-    PREFIX_CONTRACT_NAME, PREFIX_CONTRACT_VERSION, PREFIX_HASH_KEY_NAME, PREFIX_PAGE_DICTIONARY,
-    RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
+    ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ACL_WHITELIST, ALLOWED_URI_SCHEMES, ALLOW_MINTING,
+    APPROVED, ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST,
+    ARG_ADDITIONAL_REQUIRED_METADATA, ARG_ALLOWED_URI_SCHEMES, ARG_ALLOW_MINTING, ARG_APPROVE_ALL,
+    ARG_BASE_URI, ARG_BINARY_METADATA_LAYOUT, ARG_BURN_MODE, ARG_BURN_STORAGE_MODE,
+    ARG_CLAWBACK_MODE, ARG_COLLECTION_DESCRIPTION, ARG_COLLECTION_EXTERNAL_LINK,
+    ARG_COLLECTION_IMAGE, ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_URI,
+    ARG_CONTRACT_WHITELIST, ARG_DEADLINE, ARG_EVENTS_MODE, ARG_HASH_IDENTIFIER_MODE,
+    ARG_HASH_KEY_NAME_1_0_0, ARG_HOLDER_MODE, ARG_IDENTIFIER_CHARSET, ARG_IDENTIFIER_MAX_LENGTH,
+    ARG_IDENTIFIER_MIN_LENGTH, ARG_IDENTIFIER_MODE, ARG_IDENTIFIER_PREFIX, ARG_ISSUER,
+    ARG_JSON_SCHEMA, ARG_LOCKED_METADATA_FIELDS, ARG_MAX_METADATA_SIZE, ARG_METADATA_EDITOR,
+    ARG_METADATA_MUTABILITY, ARG_METADATA_STORAGE_FORMAT, ARG_METADATA_UPDATE_MODE,
+    ARG_MINTING_MODE, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND, ARG_NFT_METADATA_KIND,
+    ARG_NFT_PACKAGE_KEY, ARG_NONCE, ARG_OPERATOR, ARG_OPERATOR_BURN_MODE, ARG_OPTIONAL_METADATA,
+    ARG_ORDINAL_ASSIGNMENT_MODE, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE,
+    ARG_PACKAGE_OPERATOR_MODE, ARG_PAGE_SIZE, ARG_PAGE_START, ARG_PLACEHOLDER_URI,
+    ARG_PROVENANCE_HASH, ARG_PUBLIC_KEY, ARG_RECEIPT_NAME, ARG_REVEAL_MODE, ARG_REVISION,
+    ARG_SHUFFLE_SEED, ARG_SIGNATURE, ARG_SOURCE_KEY, ARG_SPENDER, ARG_STRICT_METADATA_VALIDATION,
+    ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_METADATA_KINDS, ARG_TOKEN_META_DATA,
+    ARG_TOKEN_META_DATA_BYTES, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY,
+    ARG_TRANSFER_FILTER_CONTRACT, ARG_WHITELIST_MODE, BASE_URI, BINARY_METADATA_LAYOUT,
+    BURNT_TOKENS, BURN_MODE, BURN_STORAGE_MODE, CLAWBACK_MODE, COLLECTION_METADATA,
+    COLLECTION_NAME, COLLECTION_SYMBOL, DEFAULT_ALLOWED_URI_SCHEMES, DEFAULT_IDENTIFIER_CHARSET,
+    ENTRY_POINT_APPROVE, ENTRY_POINT_BALANCE_OF, ENTRY_POINT_BURN, ENTRY_POINT_CIRCULATING_SUPPLY,
+    ENTRY_POINT_COLLECTION_METADATA, ENTRY_POINT_FORCE_BURN, ENTRY_POINT_FORCE_TRANSFER,
+    ENTRY_POINT_FREEZE_METADATA, ENTRY_POINT_GET_APPROVED, ENTRY_POINT_INIT, ENTRY_POINT_ISSUER_OF,
+    ENTRY_POINT_IS_APPROVED_FOR_ALL, ENTRY_POINT_METADATA, ENTRY_POINT_METADATA_AT,
+    ENTRY_POINT_METADATA_BYTES, ENTRY_POINT_METADATA_REVISION_COUNT, ENTRY_POINT_MIGRATE,
+    ENTRY_POINT_MINT, ENTRY_POINT_NUMBER_OF_BURNT_TOKENS, ENTRY_POINT_NUMBER_OF_HOLDERS,
+    ENTRY_POINT_NUMBER_OF_MINTED_TOKENS, ENTRY_POINT_OWNER_OF, ENTRY_POINT_REGISTER_OWNER,
+    ENTRY_POINT_REVEAL, ENTRY_POINT_REVOKE, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+    ENTRY_POINT_SET_JSON_SCHEMA, ENTRY_POINT_SET_TOKEN_METADATA, ENTRY_POINT_SET_VARIABLES,
+    ENTRY_POINT_TOKENS_ISSUED_BY, ENTRY_POINT_TOKEN_HASH_OF, ENTRY_POINT_TOKEN_INDEX_OF,
+    ENTRY_POINT_TOKEN_INFO, ENTRY_POINT_TOKEN_METADATA_KINDS, ENTRY_POINT_TOTAL_TOKEN_SUPPLY,
+    ENTRY_POINT_TRANSFER, ENTRY_POINT_TRANSFER_BY_SIGNATURE, ENTRY_POINT_UPDATED_RECEIPTS,
+    EVENTS_MODE, FROZEN_METADATA, HASH_BY_INDEX, HASH_IDENTIFIER_MODE, HASH_KEY_NAME_1_0_0,
+    HOLDER_MODE, IDENTIFIER_CHARSET, IDENTIFIER_MAX_LENGTH, IDENTIFIER_MIN_LENGTH, IDENTIFIER_MODE,
+    IDENTIFIER_PREFIX, INDEX_BY_HASH, INSTALLER, ISSUED_TOKENS, ISSUED_TOKEN_COUNTS,
+    ISSUED_TOKEN_RECORDS, JSON_SCHEMA, JSON_SCHEMAS, JSON_SCHEMA_VERSION,
+    MAX_CUSTOM_IDENTIFIER_LENGTH, MAX_METADATA_SIZE, MAX_TOTAL_TOKEN_SUPPLY, METADATA_BINARY,
+    METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_EDITOR, METADATA_HISTORY,
+    METADATA_MUTABILITY, METADATA_NFT721, METADATA_RAW, METADATA_REVISION_COUNTS,
+    METADATA_STORAGE_FORMAT, METADATA_UPDATE_MODE, MINTING_MODE, NFT_KIND, NFT_METADATA_KIND,
+    NFT_METADATA_KINDS, NONCES, NUMBER_OF_BURNT_TOKENS, NUMBER_OF_HOLDERS, NUMBER_OF_MINTED_TOKENS,
+    OPERATOR, OPERATORS, OPERATOR_BURN_MODE, ORDINAL_ASSIGNMENT_MODE, OWNED_TOKENS, OWNERSHIP_MODE,
+    PACKAGE_OPERATOR_MODE, PAGE_LIMIT, PAGE_TABLE, PLACEHOLDER_URI, PREFIX_ACCESS_KEY_NAME,
+    PREFIX_CEP78, PREFIX_CONTRACT_NAME, PREFIX_CONTRACT_VERSION, PREFIX_HASH_KEY_NAME,
+    PREFIX_PAGE_DICTIONARY, PROVENANCE_HASH, RECEIPT_NAME, REMINTED_TOKENS, REPORTING_MODE,
+    REVEALED, REVEAL_MODE, RLO_MFLAG, SHUFFLED_INDICES, SHUFFLE_SEED, STRICT_METADATA_VALIDATION,
+    TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_METADATA_KINDS, TOKEN_OWNERS, TOKEN_SCHEMA_VERSIONS,
     TOTAL_TOKEN_SUPPLY, TRANSFER_FILTER_CONTRACT, TRANSFER_FILTER_CONTRACT_METHOD,
     UNMATCHED_HASH_COUNT, WHITELIST_MODE,
 };
@@ -65,15 +93,18 @@ use error::NFTCoreError;
 use events::{
     events_cep47::{record_cep47_event_dictionary, CEP47Event},
     events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
-        RevokedForAll, Transfer, VariablesSet,
+        Approval, ApprovalForAll, ApprovalRevoked, Burn, CollectionMetadataUpdated, ForcedBurn,
+        ForcedTransfer, MetadataFrozen, MetadataUpdated, Migration, Mint, Revealed, RevokedForAll,
+        Transfer, VariablesSet,
     },
 };
 use metadata::CustomMetadataSchema;
 use modalities::{
-    BurnMode, EventsMode, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
-    NFTKind, NFTMetadataKind, NamedKeyConventionMode, OwnerReverseLookupMode, OwnershipMode,
-    Requirement, TokenIdentifier, TransferFilterContractResult, WhitelistMode,
+    BurnMode, BurnStorageMode, ClawbackMode, EventsMode, HashIdentifierMode, MetadataMutability,
+    MetadataStorageFormat, MetadataUpdateMode, MintingMode, NFTHolderMode, NFTIdentifierMode,
+    NFTKind, NFTMetadataKind, NamedKeyConventionMode, OrdinalAssignmentMode,
+    OwnerReverseLookupMode, OwnershipMode, Requirement, RevealMode, TokenIdentifier, TokenInfo,
+    TransferFilterContractResult, WhitelistMode,
 };
 use utils::Caller;
 
@@ -265,17 +296,124 @@ pub extern "C" fn init() {
     }
 
     // Attempt to parse the provided schema if the CustomValidated metadata kind is required or
-    // optional and fail installation if the schema cannot be parsed.
+    // optional and fail installation if the schema cannot be parsed or its constraints are
+    // unusable.
     if let Some(required_or_optional) = nft_metadata_kinds.get(&NFTMetadataKind::CustomValidated) {
         if required_or_optional == &Requirement::Required
             || required_or_optional == &Requirement::Optional
         {
             serde_json_wasm::from_str::<CustomMetadataSchema>(&json_schema)
                 .map_err(|_| NFTCoreError::InvalidJsonSchema)
+                .and_then(|schema| schema.validate())
                 .unwrap_or_revert();
         }
     }
 
+    let base_uri: String =
+        utils::get_optional_named_arg_with_user_errors(ARG_BASE_URI, NFTCoreError::InvalidBaseUri)
+            .unwrap_or_default();
+
+    let reveal_mode: RevealMode = utils::get_named_arg_with_user_errors::<u8>(
+        ARG_REVEAL_MODE,
+        NFTCoreError::MissingRevealMode,
+        NFTCoreError::InvalidRevealMode,
+    )
+    .unwrap_or_revert()
+    .try_into()
+    .unwrap_or_revert();
+
+    let provenance_hash: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_PROVENANCE_HASH,
+        NFTCoreError::InvalidProvenanceHash,
+    )
+    .unwrap_or_default();
+
+    let placeholder_uri: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_PLACEHOLDER_URI,
+        NFTCoreError::InvalidPlaceholderUri,
+    )
+    .unwrap_or_default();
+
+    if reveal_mode == RevealMode::Delayed {
+        // Only token uris derived from a base uri can be revealed later on.
+        if !nft_metadata_kinds.contains_key(&NFTMetadataKind::BaseURI) {
+            runtime::revert(NFTCoreError::InvalidRevealMode)
+        }
+        if provenance_hash.is_empty() {
+            runtime::revert(NFTCoreError::MissingProvenanceHash)
+        }
+        // The provenance hash is the base16 encoded blake2b hash of the base uri to reveal.
+        if provenance_hash.len() != 64 || !provenance_hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            runtime::revert(NFTCoreError::InvalidProvenanceHash)
+        }
+        if placeholder_uri.is_empty() {
+            runtime::revert(NFTCoreError::MissingPlaceholderUri)
+        }
+    }
+
+    // Token uris of the BaseURI metadata kind cannot be derived without a base uri, unless the
+    // base uri is only revealed later on.
+    if nft_metadata_kinds.contains_key(&NFTMetadataKind::BaseURI)
+        && base_uri.is_empty()
+        && reveal_mode == RevealMode::NoReveal
+    {
+        runtime::revert(NFTCoreError::MissingBaseUri)
+    }
+
+    let binary_metadata_layout: Vec<u8> = utils::get_optional_named_arg_with_user_errors(
+        ARG_BINARY_METADATA_LAYOUT,
+        NFTCoreError::InvalidBinaryMetadataLayout,
+    )
+    .unwrap_or_default();
+
+    // Binary metadata cannot be validated without the layout of its fields.
+    if nft_metadata_kinds.contains_key(&NFTMetadataKind::Binary) {
+        metadata::validate_binary_metadata_layout(&binary_metadata_layout).unwrap_or_revert();
+    }
+
+    let locked_metadata_fields: Vec<String> = utils::get_optional_named_arg_with_user_errors(
+        ARG_LOCKED_METADATA_FIELDS,
+        NFTCoreError::InvalidLockedMetadataFields,
+    )
+    .unwrap_or_default();
+
+    // Only fields of the built-in schemas can be locked, custom schemas lock their own properties.
+    if locked_metadata_fields
+        .iter()
+        .any(|field| !metadata::is_built_in_property(field))
+    {
+        runtime::revert(NFTCoreError::InvalidLockedMetadataFields)
+    }
+
+    let strict_metadata_validation: bool = utils::get_optional_named_arg_with_user_errors(
+        ARG_STRICT_METADATA_VALIDATION,
+        NFTCoreError::InvalidStrictMetadataValidation,
+    )
+    .unwrap_or_default();
+
+    // An empty allowlist stands for the default ipfs://, ar:// and https:// schemes.
+    let mut allowed_uri_schemes: Vec<String> = utils::get_optional_named_arg_with_user_errors(
+        ARG_ALLOWED_URI_SCHEMES,
+        NFTCoreError::InvalidAllowedUriSchemes,
+    )
+    .unwrap_or_default();
+
+    if allowed_uri_schemes.is_empty() {
+        allowed_uri_schemes = DEFAULT_ALLOWED_URI_SCHEMES
+            .iter()
+            .map(|scheme| scheme.to_string())
+            .collect();
+    } else if allowed_uri_schemes.iter().any(|scheme| scheme.is_empty()) {
+        runtime::revert(NFTCoreError::InvalidAllowedUriSchemes)
+    }
+
+    // A maximum metadata size of 0 leaves the size of the metadata unlimited.
+    let max_metadata_size: u64 = utils::get_optional_named_arg_with_user_errors(
+        ARG_MAX_METADATA_SIZE,
+        NFTCoreError::InvalidMaxMetadataSize,
+    )
+    .unwrap_or_default();
+
     let identifier_mode: NFTIdentifierMode = utils::get_named_arg_with_user_errors::<u8>(
         ARG_IDENTIFIER_MODE,
         NFTCoreError::MissingIdentifierMode,
@@ -285,6 +423,105 @@ pub extern "C" fn init() {
     .try_into()
     .unwrap_or_revert();
 
+    let ordinal_assignment_mode: OrdinalAssignmentMode =
+        utils::get_named_arg_with_user_errors::<u8>(
+            ARG_ORDINAL_ASSIGNMENT_MODE,
+            NFTCoreError::MissingOrdinalAssignmentMode,
+            NFTCoreError::InvalidOrdinalAssignmentMode,
+        )
+        .unwrap_or_revert()
+        .try_into()
+        .unwrap_or_revert();
+
+    let shuffle_seed: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_SHUFFLE_SEED,
+        NFTCoreError::InvalidShuffleSeed,
+    )
+    .unwrap_or_default();
+
+    let hash_identifier_mode: HashIdentifierMode = utils::get_named_arg_with_user_errors::<u8>(
+        ARG_HASH_IDENTIFIER_MODE,
+        NFTCoreError::MissingHashIdentifierMode,
+        NFTCoreError::InvalidHashIdentifierMode,
+    )
+    .unwrap_or_revert()
+    .try_into()
+    .unwrap_or_revert();
+
+    let identifier_charset: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_IDENTIFIER_CHARSET,
+        NFTCoreError::InvalidIdentifierRules,
+    )
+    .filter(|identifier_charset: &String| !identifier_charset.is_empty())
+    .unwrap_or_else(|| DEFAULT_IDENTIFIER_CHARSET.to_string());
+
+    let identifier_min_length: u32 = utils::get_optional_named_arg_with_user_errors(
+        ARG_IDENTIFIER_MIN_LENGTH,
+        NFTCoreError::InvalidIdentifierRules,
+    )
+    .unwrap_or(1u32);
+
+    let identifier_max_length: u32 = utils::get_optional_named_arg_with_user_errors(
+        ARG_IDENTIFIER_MAX_LENGTH,
+        NFTCoreError::InvalidIdentifierRules,
+    )
+    .unwrap_or(MAX_CUSTOM_IDENTIFIER_LENGTH);
+
+    let identifier_prefix: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_IDENTIFIER_PREFIX,
+        NFTCoreError::InvalidIdentifierRules,
+    )
+    .unwrap_or_default();
+
+    if hash_identifier_mode == HashIdentifierMode::Custom {
+        // Only hash identifiers can be provided at mint.
+        if identifier_mode != NFTIdentifierMode::Hash {
+            runtime::revert(NFTCoreError::InvalidHashIdentifierMode)
+        }
+        utils::validate_identifier_rules(
+            &identifier_charset,
+            identifier_min_length,
+            identifier_max_length,
+            &identifier_prefix,
+        )
+        .unwrap_or_revert();
+    }
+
+    let burn_storage_mode: BurnStorageMode = utils::get_named_arg_with_user_errors::<u8>(
+        ARG_BURN_STORAGE_MODE,
+        NFTCoreError::MissingBurnStorageMode,
+        NFTCoreError::InvalidBurnStorageMode,
+    )
+    .unwrap_or_revert()
+    .try_into()
+    .unwrap_or_revert();
+
+    let clawback_mode: ClawbackMode = utils::get_named_arg_with_user_errors::<u8>(
+        ARG_CLAWBACK_MODE,
+        NFTCoreError::MissingClawbackMode,
+        NFTCoreError::InvalidClawbackMode,
+    )
+    .unwrap_or_revert()
+    .try_into()
+    .unwrap_or_revert();
+
+    // Only hash identifiers can be minted again, ordinal indices are never reused.
+    if burn_storage_mode == BurnStorageMode::ReclaimAndReuse
+        && identifier_mode != NFTIdentifierMode::Hash
+    {
+        runtime::revert(NFTCoreError::InvalidBurnStorageMode)
+    }
+
+    if ordinal_assignment_mode == OrdinalAssignmentMode::Shuffled {
+        // Only ordinal indices can be shuffled.
+        if identifier_mode != NFTIdentifierMode::Ordinal {
+            runtime::revert(NFTCoreError::InvalidOrdinalAssignmentMode)
+        }
+        if shuffle_seed.is_empty() {
+            runtime::revert(NFTCoreError::MissingShuffleSeed)
+        }
+    }
+
     let metadata_mutability: MetadataMutability = utils::get_named_arg_with_user_errors::<u8>(
         ARG_METADATA_MUTABILITY,
         NFTCoreError::MissingMetadataMutability,
@@ -294,6 +531,32 @@ pub extern "C" fn init() {
     .try_into()
     .unwrap_or_revert();
 
+    let metadata_update_mode: MetadataUpdateMode = utils::get_named_arg_with_user_errors::<u8>(
+        ARG_METADATA_UPDATE_MODE,
+        NFTCoreError::MissingMetadataUpdateMode,
+        NFTCoreError::InvalidMetadataUpdateMode,
+    )
+    .unwrap_or_revert()
+    .try_into()
+    .unwrap_or_revert();
+
+    let metadata_storage_format: MetadataStorageFormat =
+        utils::get_named_arg_with_user_errors::<u8>(
+            ARG_METADATA_STORAGE_FORMAT,
+            NFTCoreError::MissingMetadataStorageFormat,
+            NFTCoreError::InvalidMetadataStorageFormat,
+        )
+        .unwrap_or_revert()
+        .try_into()
+        .unwrap_or_revert();
+
+    let metadata_editor: Option<Key> =
+        utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+            ARG_METADATA_EDITOR,
+            NFTCoreError::InvalidMetadataEditor,
+        )
+        .unwrap_or_default();
+
     let burn_mode: BurnMode = utils::get_named_arg_with_user_errors::<u8>(
         ARG_BURN_MODE,
         NFTCoreError::MissingBurnMode,
@@ -359,6 +622,12 @@ pub extern "C" fn init() {
         COLLECTION_SYMBOL,
         storage::new_uref(collection_symbol).into(),
     );
+    let mut collection_metadata: BTreeMap<String, String> = BTreeMap::new();
+    utils::set_collection_metadata_from_runtime_args(&mut collection_metadata);
+    runtime::put_key(
+        COLLECTION_METADATA,
+        storage::new_uref(collection_metadata).into(),
+    );
     runtime::put_key(
         TOTAL_TOKEN_SUPPLY,
         storage::new_uref(total_token_supply).into(),
@@ -368,7 +637,36 @@ pub extern "C" fn init() {
         storage::new_uref(ownership_mode as u8).into(),
     );
     runtime::put_key(NFT_KIND, storage::new_uref(nft_kind as u8).into());
-    runtime::put_key(JSON_SCHEMA, storage::new_uref(json_schema).into());
+    runtime::put_key(JSON_SCHEMA, storage::new_uref(json_schema.clone()).into());
+    runtime::put_key(JSON_SCHEMA_VERSION, storage::new_uref(0u32).into());
+    runtime::put_key(BASE_URI, storage::new_uref(base_uri).into());
+    runtime::put_key(REVEAL_MODE, storage::new_uref(reveal_mode as u8).into());
+    runtime::put_key(
+        PROVENANCE_HASH,
+        storage::new_uref(provenance_hash.to_lowercase()).into(),
+    );
+    runtime::put_key(PLACEHOLDER_URI, storage::new_uref(placeholder_uri).into());
+    runtime::put_key(REVEALED, storage::new_uref(false).into());
+    runtime::put_key(
+        BINARY_METADATA_LAYOUT,
+        storage::new_uref(binary_metadata_layout).into(),
+    );
+    runtime::put_key(
+        LOCKED_METADATA_FIELDS,
+        storage::new_uref(locked_metadata_fields).into(),
+    );
+    runtime::put_key(
+        STRICT_METADATA_VALIDATION,
+        storage::new_uref(strict_metadata_validation).into(),
+    );
+    runtime::put_key(
+        ALLOWED_URI_SCHEMES,
+        storage::new_uref(allowed_uri_schemes).into(),
+    );
+    runtime::put_key(
+        MAX_METADATA_SIZE,
+        storage::new_uref(max_metadata_size).into(),
+    );
     runtime::put_key(MINTING_MODE, storage::new_uref(minting_mode as u8).into());
     runtime::put_key(HOLDER_MODE, storage::new_uref(holder_mode as u8).into());
     runtime::put_key(
@@ -392,10 +690,49 @@ pub extern "C" fn init() {
         IDENTIFIER_MODE,
         storage::new_uref(identifier_mode as u8).into(),
     );
+    runtime::put_key(
+        ORDINAL_ASSIGNMENT_MODE,
+        storage::new_uref(ordinal_assignment_mode as u8).into(),
+    );
+    runtime::put_key(SHUFFLE_SEED, storage::new_uref(shuffle_seed).into());
+    runtime::put_key(
+        HASH_IDENTIFIER_MODE,
+        storage::new_uref(hash_identifier_mode as u8).into(),
+    );
+    runtime::put_key(
+        BURN_STORAGE_MODE,
+        storage::new_uref(burn_storage_mode as u8).into(),
+    );
+    runtime::put_key(CLAWBACK_MODE, storage::new_uref(clawback_mode as u8).into());
+    runtime::put_key(
+        IDENTIFIER_CHARSET,
+        storage::new_uref(identifier_charset).into(),
+    );
+    runtime::put_key(
+        IDENTIFIER_MIN_LENGTH,
+        storage::new_uref(identifier_min_length).into(),
+    );
+    runtime::put_key(
+        IDENTIFIER_MAX_LENGTH,
+        storage::new_uref(identifier_max_length).into(),
+    );
+    runtime::put_key(
+        IDENTIFIER_PREFIX,
+        storage::new_uref(identifier_prefix).into(),
+    );
     runtime::put_key(
         METADATA_MUTABILITY,
         storage::new_uref(metadata_mutability as u8).into(),
     );
+    runtime::put_key(
+        METADATA_UPDATE_MODE,
+        storage::new_uref(metadata_update_mode as u8).into(),
+    );
+    runtime::put_key(
+        METADATA_STORAGE_FORMAT,
+        storage::new_uref(metadata_storage_format as u8).into(),
+    );
+    runtime::put_key(METADATA_EDITOR, storage::new_uref(metadata_editor).into());
     runtime::put_key(BURN_MODE, storage::new_uref(burn_mode as u8).into());
     runtime::put_key(
         OPERATOR_BURN_MODE,
@@ -423,17 +760,28 @@ pub extern "C" fn init() {
     // This is an internal variable that the installing account cannot change
     // but is incremented by the contract itself.
     runtime::put_key(NUMBER_OF_MINTED_TOKENS, storage::new_uref(0u64).into());
+    // Likewise the supply statistics are maintained by mint, burn and transfer.
+    runtime::put_key(NUMBER_OF_BURNT_TOKENS, storage::new_uref(0u64).into());
+    runtime::put_key(NUMBER_OF_HOLDERS, storage::new_uref(0u64).into());
 
     // Create the data dictionaries to store essential values, topically.
     storage::new_dictionary(TOKEN_OWNERS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(TOKEN_ISSUERS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(ISSUED_TOKENS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(ISSUED_TOKEN_COUNTS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(ISSUED_TOKEN_RECORDS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(APPROVED).unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(OPERATORS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(BURNT_TOKENS)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(REMINTED_TOKENS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(TOKEN_COUNT)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(METADATA_CUSTOM_VALIDATED)
@@ -444,6 +792,12 @@ pub extern "C" fn init() {
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(METADATA_RAW)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(METADATA_BINARY)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(TOKEN_METADATA_KINDS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(SHUFFLED_INDICES)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(HASH_BY_INDEX)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(INDEX_BY_HASH)
@@ -452,6 +806,20 @@ pub extern "C" fn init() {
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     storage::new_dictionary(ACL_WHITELIST)
         .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(NONCES).unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(JSON_SCHEMAS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(TOKEN_SCHEMA_VERSIONS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(FROZEN_METADATA)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(METADATA_HISTORY)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(METADATA_REVISION_COUNTS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+
+    // The installation schema is the first version of the json schema.
+    utils::upsert_dictionary_value_from_key(JSON_SCHEMAS, &0u32.to_string(), json_schema);
 
     for key in acl_whitelist.iter() {
         utils::upsert_dictionary_value_from_key(
@@ -553,6 +921,62 @@ pub extern "C" fn set_variables() {
         storage::write(operator_burn_mode_uref, operator_burn_mode);
     }
 
+    // Passing None removes the metadata editor.
+    if let Some(metadata_editor) = utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+        ARG_METADATA_EDITOR,
+        NFTCoreError::InvalidMetadataEditor,
+    ) {
+        // Contracts installed before the delegated metadata update mode have no editor yet.
+        if utils::named_uref_exists(METADATA_EDITOR) {
+            let metadata_editor_uref = utils::get_uref(
+                METADATA_EDITOR,
+                NFTCoreError::MissingMetadataEditor,
+                NFTCoreError::InvalidMetadataEditor,
+            );
+            storage::write(metadata_editor_uref, metadata_editor);
+        } else {
+            runtime::put_key(METADATA_EDITOR, storage::new_uref(metadata_editor).into());
+        }
+    }
+
+    if let Some(base_uri) = utils::get_optional_named_arg_with_user_errors::<String>(
+        ARG_BASE_URI,
+        NFTCoreError::InvalidBaseUri,
+    ) {
+        let metadata_kinds: BTreeMap<NFTMetadataKind, Requirement> =
+            utils::get_stored_value_with_user_errors(
+                NFT_METADATA_KINDS,
+                NFTCoreError::MissingNFTMetadataKind,
+                NFTCoreError::InvalidNFTMetadataKind,
+            );
+        if metadata_kinds.contains_key(&NFTMetadataKind::BaseURI) && base_uri.is_empty() {
+            runtime::revert(NFTCoreError::MissingBaseUri)
+        }
+        // The base uri of a delayed reveal is committed to by the provenance hash and can only be
+        // set through reveal.
+        if utils::get_reveal_mode() == RevealMode::Delayed {
+            runtime::revert(NFTCoreError::BaseUriLocked)
+        }
+        let base_uri_uref = utils::get_uref(
+            BASE_URI,
+            NFTCoreError::MissingBaseUri,
+            NFTCoreError::InvalidBaseUri,
+        );
+        storage::write(base_uri_uref, base_uri);
+    }
+
+    let mut collection_metadata = utils::get_collection_metadata();
+    let is_collection_metadata_updated =
+        utils::set_collection_metadata_from_runtime_args(&mut collection_metadata);
+    if is_collection_metadata_updated {
+        let collection_metadata_uref = utils::get_uref(
+            COLLECTION_METADATA,
+            NFTCoreError::MissingCollectionMetadata,
+            NFTCoreError::InvalidCollectionMetadata,
+        );
+        storage::write(collection_metadata_uref, collection_metadata.clone());
+    }
+
     let mut new_acl_whitelist = utils::get_optional_named_arg_with_user_errors::<Vec<Key>>(
         ARG_ACL_WHITELIST,
         NFTCoreError::InvalidACLWhitelist,
@@ -612,6 +1036,166 @@ pub extern "C" fn set_variables() {
         EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::VariablesSet),
         EventsMode::CES => casper_event_standard::emit(VariablesSet::new()),
     }
+
+    // Emit CollectionMetadataUpdated event.
+    if is_collection_metadata_updated {
+        match events_mode {
+            EventsMode::NoEvents => {}
+            EventsMode::CEP47 => {
+                record_cep47_event_dictionary(CEP47Event::CollectionMetadataUpdate)
+            }
+            EventsMode::CES => {
+                let field = |name: &str| collection_metadata.get(name).cloned().unwrap_or_default();
+                casper_event_standard::emit(CollectionMetadataUpdated::new(
+                    field("contract_uri"),
+                    field("description"),
+                    field("image"),
+                    field("external_link"),
+                ))
+            }
+        }
+    }
+}
+
+// Returns the collection metadata, the collection-wide information beyond its name and symbol.
+#[no_mangle]
+pub extern "C" fn collection_metadata() {
+    let collection_metadata = utils::get_collection_metadata();
+    runtime::ret(
+        CLValue::from_t(collection_metadata)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Publishes a new version of the json schema used to validate CustomValidated metadata. Previous
+// versions are kept in the json_schemas dictionary, while subsequent mint and set_token_metadata
+// calls validate against the newest version.
+#[no_mangle]
+pub extern "C" fn set_json_schema() {
+    let installer = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+
+    // Only the installing account can publish a new json schema.
+    if installer != runtime::get_caller() {
+        runtime::revert(NFTCoreError::InvalidAccount);
+    }
+
+    let json_schema: String = utils::get_named_arg_with_user_errors(
+        ARG_JSON_SCHEMA,
+        NFTCoreError::MissingJsonSchema,
+        NFTCoreError::InvalidJsonSchema,
+    )
+    .unwrap_or_revert();
+
+    if json_schema.is_empty() {
+        runtime::revert(NFTCoreError::MissingJsonSchema)
+    }
+
+    serde_json_wasm::from_str::<CustomMetadataSchema>(&json_schema)
+        .map_err(|_| NFTCoreError::InvalidJsonSchema)
+        .and_then(|schema| schema.validate())
+        .unwrap_or_revert();
+
+    let json_schema_version = utils::get_json_schema_version()
+        .checked_add(1)
+        .unwrap_or_revert_with(NFTCoreError::InvalidJsonSchemaVersion);
+
+    utils::upsert_dictionary_value_from_key(
+        JSON_SCHEMAS,
+        &json_schema_version.to_string(),
+        json_schema.clone(),
+    );
+
+    let json_schema_uref = utils::get_uref(
+        JSON_SCHEMA,
+        NFTCoreError::MissingJsonSchema,
+        NFTCoreError::InvalidJsonSchema,
+    );
+    storage::write(json_schema_uref, json_schema);
+
+    let json_schema_version_uref = utils::get_uref(
+        JSON_SCHEMA_VERSION,
+        NFTCoreError::MissingJsonSchemaVersion,
+        NFTCoreError::InvalidJsonSchemaVersion,
+    );
+    storage::write(json_schema_version_uref, json_schema_version);
+}
+
+// Reveals the base uri committed to at installation in the Delayed reveal mode. The blake2b hash
+// of the base uri must match the provenance hash, and tokens point to the placeholder uri until
+// then.
+#[no_mangle]
+pub extern "C" fn reveal() {
+    let installer = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+
+    // Only the installing account can reveal the base uri.
+    if installer != runtime::get_caller() {
+        runtime::revert(NFTCoreError::InvalidAccount);
+    }
+
+    if utils::get_reveal_mode() != RevealMode::Delayed {
+        runtime::revert(NFTCoreError::InvalidRevealMode)
+    }
+
+    if utils::is_revealed() {
+        runtime::revert(NFTCoreError::AlreadyRevealed)
+    }
+
+    let base_uri: String = utils::get_named_arg_with_user_errors(
+        ARG_BASE_URI,
+        NFTCoreError::MissingBaseUri,
+        NFTCoreError::InvalidBaseUri,
+    )
+    .unwrap_or_revert();
+
+    if base_uri.is_empty() {
+        runtime::revert(NFTCoreError::MissingBaseUri)
+    }
+
+    let provenance_hash = utils::get_stored_value_with_user_errors::<String>(
+        PROVENANCE_HASH,
+        NFTCoreError::MissingProvenanceHash,
+        NFTCoreError::InvalidProvenanceHash,
+    );
+
+    if base16::encode_lower(&runtime::blake2b(base_uri.as_bytes())) != provenance_hash {
+        runtime::revert(NFTCoreError::ProvenanceHashMismatch)
+    }
+
+    let base_uri_uref = utils::get_uref(
+        BASE_URI,
+        NFTCoreError::MissingBaseUri,
+        NFTCoreError::InvalidBaseUri,
+    );
+    storage::write(base_uri_uref, base_uri.clone());
+
+    let revealed_uref = utils::get_uref(
+        REVEALED,
+        NFTCoreError::MissingRevealed,
+        NFTCoreError::InvalidRevealed,
+    );
+    storage::write(revealed_uref, true);
+
+    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    ))
+    .unwrap_or_revert();
+
+    // Emit Revealed event.
+    match events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CES => casper_event_standard::emit(Revealed::new(base_uri, provenance_hash)),
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Revealed),
+    }
 }
 
 // Mints a new token. Minting will fail if allow_minting is set to false.
@@ -724,6 +1308,8 @@ pub extern "C" fn mint() {
             NFTCoreError::InvalidNFTMetadataKind,
         );
 
+    utils::check_token_metadata_size();
+
     let token_metadata = utils::get_named_arg_with_user_errors::<String>(
         ARG_TOKEN_META_DATA,
         NFTCoreError::MissingTokenMetaData,
@@ -731,6 +1317,23 @@ pub extern "C" fn mint() {
     )
     .unwrap_or_revert();
 
+    // Metadata of the Binary kind is passed as bytes alongside the string metadata.
+    let token_metadata_bytes: Bytes = utils::get_optional_named_arg_with_user_errors(
+        ARG_TOKEN_META_DATA_BYTES,
+        NFTCoreError::InvalidTokenMetaData,
+    )
+    .unwrap_or_default();
+
+    // The optional kinds to populate for this token, which must then be valid. Without a selection
+    // the optional kinds are populated whenever the metadata happens to be valid for them.
+    let token_metadata_kinds: Option<Vec<u8>> = utils::get_optional_named_arg_with_user_errors(
+        ARG_TOKEN_METADATA_KINDS,
+        NFTCoreError::InvalidTokenMetadataKinds,
+    );
+    let has_token_metadata_kinds = token_metadata_kinds.is_some();
+    let metadata_kinds =
+        utils::get_token_metadata_requirements(metadata_kinds, token_metadata_kinds);
+
     let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
         IDENTIFIER_MODE,
         NFTCoreError::MissingIdentifierMode,
@@ -746,18 +1349,53 @@ pub extern "C" fn mint() {
     )
     .unwrap_or_default();
     let token_identifier: TokenIdentifier = match identifier_mode {
-        NFTIdentifierMode::Ordinal => TokenIdentifier::Index(minted_tokens_count),
-        NFTIdentifierMode::Hash => TokenIdentifier::Hash(if optional_token_hash.is_empty() {
-            base16::encode_lower(&runtime::blake2b(token_metadata.clone()))
-        } else {
-            optional_token_hash
+        NFTIdentifierMode::Ordinal => match utils::get_ordinal_assignment_mode() {
+            OrdinalAssignmentMode::Sequential => TokenIdentifier::Index(minted_tokens_count),
+            OrdinalAssignmentMode::Shuffled => TokenIdentifier::Index(
+                utils::assign_shuffled_index(minted_tokens_count, total_token_supply),
+            ),
+        },
+        NFTIdentifierMode::Hash => TokenIdentifier::Hash(if !optional_token_hash.is_empty() {
+            if utils::get_hash_identifier_mode() == HashIdentifierMode::Custom {
+                utils::validate_custom_identifier(&optional_token_hash).unwrap_or_revert();
+            }
+            optional_token_hash
+        } else if utils::get_hash_identifier_mode() == HashIdentifierMode::Custom {
+            // Custom identifiers cannot be derived from the metadata.
+            runtime::revert(NFTCoreError::MissingTokenID)
+        } else if token_metadata.is_empty() && !token_metadata_bytes.is_empty() {
+            base16::encode_lower(&runtime::blake2b(&token_metadata_bytes))
+        } else {
+            base16::encode_lower(&runtime::blake2b(token_metadata.clone()))
         }),
     };
 
+    // The kinds of metadata the token is minted with.
+    let mut populated_metadata_kinds: Vec<u8> = vec![];
     for (metadata_kind, required) in metadata_kinds {
         if required == Requirement::Unneeded {
             continue;
         }
+        // Token uris of the BaseURI kind are derived from the base uri rather than stored.
+        if metadata_kind == NFTMetadataKind::BaseURI {
+            populated_metadata_kinds.push(metadata_kind as u8);
+            continue;
+        }
+        if metadata_kind == NFTMetadataKind::Binary {
+            match metadata::validate_binary_metadata(&token_metadata_bytes) {
+                Ok(()) => {
+                    utils::upsert_dictionary_value_from_key(
+                        METADATA_BINARY,
+                        &token_identifier.get_dictionary_item_key(),
+                        token_metadata_bytes.clone(),
+                    );
+                    populated_metadata_kinds.push(metadata_kind as u8);
+                }
+                Err(err) if required == Requirement::Required => runtime::revert(err),
+                Err(_) => {}
+            }
+            continue;
+        }
         let token_metadata_validation =
             metadata::validate_metadata(&metadata_kind, token_metadata.clone());
         match token_metadata_validation {
@@ -767,6 +1405,15 @@ pub extern "C" fn mint() {
                     &token_identifier.get_dictionary_item_key(),
                     validated_token_metadata,
                 );
+                populated_metadata_kinds.push(metadata_kind as u8);
+                // Record the json schema version the custom metadata was validated against.
+                if metadata_kind == NFTMetadataKind::CustomValidated {
+                    utils::upsert_dictionary_value_from_key(
+                        TOKEN_SCHEMA_VERSIONS,
+                        &token_identifier.get_dictionary_item_key(),
+                        Some(utils::get_json_schema_version()),
+                    );
+                }
             }
             Err(err) => {
                 if required == Requirement::Required {
@@ -796,6 +1443,20 @@ pub extern "C" fn mint() {
         &token_identifier.get_dictionary_item_key(),
         caller,
     );
+    utils::record_issued_token(caller, &token_identifier);
+    // Only a selection is recorded, tokens minted without one follow the requirements of the
+    // collection.
+    if has_token_metadata_kinds {
+        utils::upsert_dictionary_value_from_key(
+            TOKEN_METADATA_KINDS,
+            &token_identifier.get_dictionary_item_key(),
+            Some(populated_metadata_kinds),
+        );
+    }
+
+    // The minted metadata is the first revision of the metadata history, whatever the metadata
+    // mutability.
+    utils::record_metadata_revision(&token_identifier, caller);
     let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner_key);
 
     if let NFTIdentifierMode::Hash = identifier_mode {
@@ -814,6 +1475,7 @@ pub extern "C" fn mint() {
         &owned_tokens_item_key,
         updated_token_count,
     );
+    utils::update_number_of_holders(updated_token_count - 1u64, updated_token_count);
 
     // Increment number_of_minted_tokens by one
     let number_of_minted_tokens_uref = utils::get_uref(
@@ -823,6 +1485,8 @@ pub extern "C" fn mint() {
     );
     storage::write(number_of_minted_tokens_uref, minted_tokens_count + 1u64);
 
+    let memo = utils::get_optional_memo();
+
     // Emit Mint event.
     let events_mode: EventsMode =
         EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
@@ -838,10 +1502,12 @@ pub extern "C" fn mint() {
             token_owner_key,
             token_identifier.clone(),
             token_metadata,
+            memo,
         )),
         EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Mint {
             recipient: token_owner_key,
             token_id: token_identifier.clone(),
+            memo,
         }),
     }
 
@@ -853,11 +1519,10 @@ pub extern "C" fn mint() {
             utils::migrate_token_hashes(token_owner_key)
         }
 
-        let (page_table_entry, page_uref) = utils::add_page_entry_and_page_record(
-            minted_tokens_count,
-            &owned_tokens_item_key,
-            true,
-        );
+        // Shuffled tokens are recorded at the page of their index rather than their mint order.
+        let token_index = token_identifier.get_index().unwrap_or(minted_tokens_count);
+        let (page_table_entry, page_uref) =
+            utils::add_page_entry_and_page_record(token_index, &owned_tokens_item_key, true);
 
         let receipt_string = utils::get_receipt_name(page_table_entry);
         let receipt_address = Key::dictionary(page_uref, owned_tokens_item_key.as_bytes());
@@ -894,11 +1559,12 @@ pub extern "C" fn burn() {
             }
         };
 
-    let token_owner = match utils::get_dictionary_value_from_key::<Key>(
-        TOKEN_OWNERS,
-        &token_identifier.get_dictionary_item_key(),
-    ) {
+    let token_owner = match utils::get_token_owner(&token_identifier) {
         Some(owner) => owner,
+        // The owner of a burnt token is cleared when its storage is reclaimed.
+        None if utils::is_token_burned(&token_identifier) => {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+        }
         None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
     };
 
@@ -941,6 +1607,77 @@ pub extern "C" fn burn() {
         runtime::revert(NFTCoreError::InvalidTokenOwner)
     };
 
+    burn_token(
+        token_identifier,
+        token_owner,
+        caller,
+        false,
+        utils::get_optional_memo(),
+    )
+}
+
+// Burns a token on behalf of the installer, without the consent of its owner. Only available when
+// the contract was installed with the Enabled clawback mode.
+#[no_mangle]
+pub extern "C" fn force_burn() {
+    if let BurnMode::NonBurnable = utils::get_burn_mode() {
+        runtime::revert(NFTCoreError::InvalidBurnMode)
+    }
+
+    let caller = ensure_clawback_by_installer();
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    let token_owner = match utils::get_token_owner(&token_identifier) {
+        Some(owner) => owner,
+        None if utils::is_token_burned(&token_identifier) => {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+        }
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    burn_token(token_identifier, token_owner, caller, true, None)
+}
+
+// Reverts unless the contract was installed with the Enabled clawback mode and the caller is the
+// installer. Returns the caller.
+fn ensure_clawback_by_installer() -> Key {
+    if ClawbackMode::Enabled != utils::get_clawback_mode() {
+        runtime::revert(NFTCoreError::InvalidClawbackMode)
+    }
+
+    let installer = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+
+    // Only the installing account can seize tokens.
+    if installer != runtime::get_caller() {
+        runtime::revert(NFTCoreError::InvalidAccount);
+    }
+
+    Key::Account(installer)
+}
+
+// Marks an already authorized token as burnt, reclaims its storage according to the burn storage
+// mode, updates the balance of its owner and emits the Burn event carrying the memo, or the
+// ForcedBurn event when the burn was forced by the installer.
+fn burn_token(
+    token_identifier: TokenIdentifier,
+    token_owner: Key,
+    caller: Key,
+    forced: bool,
+    memo: Option<String>,
+) {
     // It makes sense to keep this token as owned by the caller. It just happens that the caller
     // owns a burnt token. That's all. Similarly, we should probably also not change the
     // owned_tokens dictionary.
@@ -955,6 +1692,19 @@ pub extern "C" fn burn() {
         (),
     );
 
+    match utils::get_burn_storage_mode() {
+        BurnStorageMode::Retain => {}
+        BurnStorageMode::Reclaim => reclaim_burnt_token_storage(&token_identifier),
+        BurnStorageMode::ReclaimAndReuse => {
+            reclaim_burnt_token_storage(&token_identifier);
+            utils::upsert_dictionary_value_from_key(
+                REMINTED_TOKENS,
+                &token_identifier.get_dictionary_item_key(),
+                false,
+            );
+        }
+    }
+
     let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner);
 
     let updated_balance =
@@ -974,6 +1724,8 @@ pub extern "C" fn burn() {
         };
 
     utils::upsert_dictionary_value_from_key(TOKEN_COUNT, &owned_tokens_item_key, updated_balance);
+    utils::update_number_of_holders(updated_balance + 1u64, updated_balance);
+    utils::increment_number_of_burnt_tokens();
 
     // Emit Burn event.
     let events_mode: EventsMode =
@@ -984,12 +1736,21 @@ pub extern "C" fn burn() {
         ))
         .unwrap_or_revert();
 
-    match events_mode {
-        EventsMode::NoEvents => {}
-        EventsMode::CES => {
-            casper_event_standard::emit(Burn::new(token_owner, token_identifier, caller))
+    match (events_mode, forced) {
+        (EventsMode::NoEvents, _) => {}
+        (EventsMode::CES, false) => {
+            casper_event_standard::emit(Burn::new(token_owner, token_identifier, caller, memo))
         }
-        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Burn {
+        (EventsMode::CES, true) => {
+            casper_event_standard::emit(ForcedBurn::new(token_owner, token_identifier, caller))
+        }
+        (EventsMode::CEP47, false) => record_cep47_event_dictionary(CEP47Event::Burn {
+            owner: token_owner,
+            token_id: token_identifier,
+            burner: caller,
+            memo,
+        }),
+        (EventsMode::CEP47, true) => record_cep47_event_dictionary(CEP47Event::ForcedBurn {
             owner: token_owner,
             token_id: token_identifier,
             burner: caller,
@@ -997,6 +1758,102 @@ pub extern "C" fn burn() {
     }
 }
 
+// Overwrites the owner, approval, metadata and hash lookup entries of a burnt token with empty
+// values, so that a hash minted again starts from a clean state. Dictionary entries cannot be
+// removed, so the owner entry is overwritten with a tombstone, and the index lookup of the hash is
+// kept.
+fn reclaim_burnt_token_storage(token_identifier: &TokenIdentifier) {
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+
+    utils::clear_token_owner(token_identifier);
+    utils::upsert_dictionary_value_from_key(
+        APPROVED,
+        &token_identifier_dictionary_key,
+        Option::<Key>::None,
+    );
+
+    for metadata_kind in utils::get_token_metadata_kinds(token_identifier) {
+        match NFTMetadataKind::try_from(metadata_kind).unwrap_or_revert() {
+            // Token uris of the BaseURI kind are derived from the base uri rather than stored.
+            NFTMetadataKind::BaseURI => {}
+            NFTMetadataKind::Binary => utils::upsert_dictionary_value_from_key(
+                METADATA_BINARY,
+                &token_identifier_dictionary_key,
+                Bytes::new(),
+            ),
+            kind => utils::upsert_dictionary_value_from_key(
+                &metadata::get_metadata_dictionary_name(&kind),
+                &token_identifier_dictionary_key,
+                String::new(),
+            ),
+        }
+    }
+    utils::upsert_dictionary_value_from_key(
+        TOKEN_METADATA_KINDS,
+        &token_identifier_dictionary_key,
+        Option::<Vec<u8>>::None,
+    );
+
+    for revision in 0..utils::get_metadata_revision_count(token_identifier) {
+        let revision_item_key = utils::get_metadata_revision_item_key(token_identifier, revision);
+        if let Some((_, block_time, updater)) = utils::get_dictionary_value_from_key::<(
+            BTreeMap<u8, String>,
+            u64,
+            Key,
+        )>(METADATA_HISTORY, &revision_item_key)
+        {
+            utils::upsert_dictionary_value_from_key(
+                METADATA_HISTORY,
+                &revision_item_key,
+                (BTreeMap::<u8, String>::new(), block_time, updater),
+            );
+        }
+    }
+    utils::upsert_dictionary_value_from_key(
+        METADATA_REVISION_COUNTS,
+        &token_identifier_dictionary_key,
+        0u64,
+    );
+
+    if utils::is_metadata_frozen(token_identifier) {
+        utils::upsert_dictionary_value_from_key(
+            FROZEN_METADATA,
+            &token_identifier_dictionary_key,
+            false,
+        );
+    }
+    if utils::get_dictionary_value_from_key::<Option<u32>>(
+        TOKEN_SCHEMA_VERSIONS,
+        &token_identifier_dictionary_key,
+    )
+    .is_some()
+    {
+        utils::upsert_dictionary_value_from_key(
+            TOKEN_SCHEMA_VERSIONS,
+            &token_identifier_dictionary_key,
+            Option::<u32>::None,
+        );
+    }
+
+    // Only tokens of the Hash identifier mode have a hash lookup.
+    let token_index = match token_identifier {
+        TokenIdentifier::Index(_) => None,
+        TokenIdentifier::Hash(_) => utils::get_dictionary_value_from_key::<u64>(
+            INDEX_BY_HASH,
+            &token_identifier_dictionary_key,
+        ),
+    };
+    if let Some(token_index) = token_index {
+        if utils::get_token_hash(token_index).is_some() {
+            utils::upsert_dictionary_value_from_key(
+                HASH_BY_INDEX,
+                &token_index.to_string(),
+                String::new(),
+            );
+        }
+    }
+}
+
 // Marks an account as approved for an identified token transfer
 #[no_mangle]
 pub extern "C" fn approve() {
@@ -1027,26 +1884,22 @@ pub extern "C" fn approve() {
     let token_id = utils::get_token_identifier_from_runtime_args(&identifier_mode);
     let token_identifier_dictionary_key = token_id.get_dictionary_item_key();
 
-    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingNumberOfMintedTokens,
-        NFTCoreError::InvalidNumberOfMintedTokens,
-    );
+    let token_index_bound = utils::get_token_index_bound();
 
     if let NFTIdentifierMode::Ordinal = identifier_mode {
         // Revert if token_id is out of bounds
         if let TokenIdentifier::Index(index) = &token_id {
-            if *index >= number_of_minted_tokens {
+            if *index >= token_index_bound {
                 runtime::revert(NFTCoreError::InvalidTokenIdentifier);
             }
         }
     }
 
-    let owner = match utils::get_dictionary_value_from_key::<Key>(
-        TOKEN_OWNERS,
-        &token_identifier_dictionary_key,
-    ) {
+    let owner = match utils::get_token_owner(&token_id) {
         Some(owner) => owner,
+        None if utils::is_token_burned(&token_id) => {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+        }
         None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
     };
 
@@ -1163,26 +2016,22 @@ pub extern "C" fn revoke() {
     let token_id = utils::get_token_identifier_from_runtime_args(&identifier_mode);
     let token_identifier_dictionary_key = token_id.get_dictionary_item_key();
 
-    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingNumberOfMintedTokens,
-        NFTCoreError::InvalidNumberOfMintedTokens,
-    );
+    let token_index_bound = utils::get_token_index_bound();
 
     if let NFTIdentifierMode::Ordinal = identifier_mode {
         // Revert if token_id is out of bounds
         if let TokenIdentifier::Index(index) = &token_id {
-            if *index >= number_of_minted_tokens {
+            if *index >= token_index_bound {
                 runtime::revert(NFTCoreError::InvalidTokenIdentifier);
             }
         }
     }
 
-    let owner = match utils::get_dictionary_value_from_key::<Key>(
-        TOKEN_OWNERS,
-        &token_identifier_dictionary_key,
-    ) {
+    let owner = match utils::get_token_owner(&token_id) {
         Some(owner) => owner,
+        None if utils::is_token_burned(&token_id) => {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+        }
         None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
     };
 
@@ -1398,10 +2247,7 @@ pub extern "C" fn transfer() {
         runtime::revert(NFTCoreError::PreviouslyBurntToken)
     }
 
-    let owner = match utils::get_dictionary_value_from_key::<Key>(
-        TOKEN_OWNERS,
-        &token_identifier.get_dictionary_item_key(),
-    ) {
+    let owner = match utils::get_token_owner(&token_identifier) {
         Some(owner) => owner,
         None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
     };
@@ -1474,101 +2320,318 @@ pub extern "C" fn transfer() {
     )
     .unwrap_or_revert();
 
-    if NFTIdentifierMode::Hash == identifier_mode && runtime::get_key(OWNED_TOKENS).is_some() {
-        if utils::should_migrate_token_hashes(source_owner_key) {
-            utils::migrate_token_hashes(source_owner_key)
-        }
+    transfer_token(
+        identifier_mode,
+        token_identifier,
+        source_owner_key,
+        target_owner_key,
+        caller,
+        false,
+        utils::get_optional_memo(),
+    )
+}
 
-        if utils::should_migrate_token_hashes(target_owner_key) {
-            utils::migrate_token_hashes(target_owner_key)
-        }
+// Transfers a token on behalf of the installer, without the consent of its owner. Only available
+// when the contract was installed with the Enabled clawback mode.
+#[no_mangle]
+pub extern "C" fn force_transfer() {
+    // Assigned tokens can be reassigned by the installer, while tokens of the Minter ownership
+    // mode always belong to their minter.
+    if let OwnershipMode::Minter = utils::get_ownership_mode().unwrap_or_revert() {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
     }
 
-    let target_owner_item_key = utils::encode_dictionary_item_key(target_owner_key);
+    let caller = ensure_clawback_by_installer();
 
-    // Updated token_owners dictionary. Revert if token_owner not found.
-    match utils::get_dictionary_value_from_key::<Key>(
-        TOKEN_OWNERS,
-        &token_identifier.get_dictionary_item_key(),
-    ) {
-        Some(token_actual_owner) => {
-            if token_actual_owner != source_owner_key {
-                runtime::revert(NFTCoreError::InvalidTokenOwner)
-            }
-            utils::upsert_dictionary_value_from_key(
-                TOKEN_OWNERS,
-                &token_identifier.get_dictionary_item_key(),
-                target_owner_key,
-            );
-        }
-        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
-    }
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
 
-    let source_owner_item_key = utils::encode_dictionary_item_key(source_owner_key);
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
 
-    // Update the from_account balance
-    let updated_from_account_balance =
-        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &source_owner_item_key) {
-            Some(balance) => {
-                if balance > 0u64 {
-                    balance - 1u64
-                } else {
-                    // This should never happen...
-                    runtime::revert(NFTCoreError::FatalTokenIdDuplication);
-                }
-            }
-            None => {
-                // This should never happen...
-                runtime::revert(NFTCoreError::FatalTokenIdDuplication);
-            }
-        };
-    utils::upsert_dictionary_value_from_key(
-        TOKEN_COUNT,
-        &source_owner_item_key,
-        updated_from_account_balance,
-    );
+    if utils::is_token_burned(&token_identifier) {
+        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    }
 
-    // Update the to_account balance
-    let updated_to_account_balance =
-        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &target_owner_item_key) {
-            Some(balance) => balance + 1u64,
-            None => 1u64,
-        };
+    let owner = match utils::get_token_owner(&token_identifier) {
+        Some(owner) => owner,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
 
-    utils::upsert_dictionary_value_from_key(
-        TOKEN_COUNT,
-        &target_owner_item_key,
-        updated_to_account_balance,
-    );
+    let target_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TARGET_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
 
-    utils::upsert_dictionary_value_from_key(
-        APPROVED,
-        &token_identifier.get_dictionary_item_key(),
-        Option::<Key>::None,
-    );
+    transfer_token(
+        identifier_mode,
+        token_identifier,
+        owner,
+        target_owner_key,
+        caller,
+        true,
+        None,
+    )
+}
 
-    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
-        EVENTS_MODE,
-        NFTCoreError::MissingEventsMode,
-        NFTCoreError::InvalidEventsMode,
-    ))
-    .unwrap_or_revert();
+// Transfers a token on behalf of its owner using an authorization signed by the owner's key, so
+// that a relayer can pay for the deploy. The signed message is the blake2b hash of the serialized
+// (contract package hash, token identifier, target key, nonce, deadline) tuple. Each owner account
+// has a nonce which must match and is incremented on success, preventing replays.
+#[no_mangle]
+pub extern "C" fn transfer_by_signature() {
+    // The caller is the relayer paying for the deploy, not the token owner.
+    let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
+        Caller::Session(account_hash) => account_hash.into(),
+        Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
+    };
 
-    match events_mode {
-        EventsMode::NoEvents => {}
-        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Transfer {
-            sender: caller,
-            recipient: target_owner_key,
-            token_id: token_identifier.clone(),
-        }),
-        EventsMode::CES => {
-            // Emit Transfer event.
-            let spender = if caller == owner { None } else { Some(caller) };
-            casper_event_standard::emit(Transfer::new(
-                owner,
+    // If the optional filter contract modality is being used, revert unless called specifically
+    // by that filter contract
+    if let Some(filter_contract) = utils::get_transfer_filter_contract() {
+        let caller_is_filter = caller == filter_contract.into();
+        if !caller_is_filter {
+            runtime::revert(NFTCoreError::CallerMustBeTransferFilter);
+        }
+    }
+
+    if let OwnershipMode::Minter | OwnershipMode::Assigned =
+        utils::get_ownership_mode().unwrap_or_revert()
+    {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
+    }
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    // We assume we cannot transfer burnt tokens
+    if utils::is_token_burned(&token_identifier) {
+        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    }
+
+    let owner = match utils::get_token_owner(&token_identifier) {
+        Some(owner) => owner,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    let target_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TARGET_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    let nonce = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_NONCE,
+        NFTCoreError::MissingNonce,
+        NFTCoreError::InvalidNonce,
+    )
+    .unwrap_or_revert();
+
+    let deadline = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_DEADLINE,
+        NFTCoreError::MissingDeadline,
+        NFTCoreError::InvalidDeadline,
+    )
+    .unwrap_or_revert();
+
+    if u64::from(runtime::get_blocktime()) > deadline {
+        runtime::revert(NFTCoreError::ExpiredTransferAuthorization)
+    }
+
+    let public_key = utils::get_named_arg_with_user_errors::<PublicKey>(
+        ARG_PUBLIC_KEY,
+        NFTCoreError::MissingPublicKey,
+        NFTCoreError::InvalidPublicKey,
+    )
+    .unwrap_or_revert();
+
+    // Only the owning account can authorize the transfer of its token.
+    if owner != Key::Account(public_key.to_account_hash()) {
+        runtime::revert(NFTCoreError::InvalidTokenOwner)
+    }
+
+    let signature = utils::get_named_arg_with_user_errors::<Bytes>(
+        ARG_SIGNATURE,
+        NFTCoreError::MissingSignature,
+        NFTCoreError::InvalidSignature,
+    )
+    .map(|signature_bytes| {
+        bytesrepr::deserialize::<Signature>(signature_bytes.into())
+            .unwrap_or_revert_with(NFTCoreError::InvalidSignature)
+    })
+    .unwrap_or_revert();
+
+    let owner_item_key = utils::encode_dictionary_item_key(owner);
+    let expected_nonce =
+        utils::get_dictionary_value_from_key::<u64>(NONCES, &owner_item_key).unwrap_or_default();
+    if nonce != expected_nonce {
+        runtime::revert(NFTCoreError::InvalidNonce)
+    }
+
+    let message =
+        utils::transfer_authorization_digest(&token_identifier, &target_owner_key, nonce, deadline);
+    if crypto::verify(message, &signature, &public_key).is_err() {
+        runtime::revert(NFTCoreError::InvalidSignature)
+    }
+
+    utils::upsert_dictionary_value_from_key(NONCES, &owner_item_key, nonce + 1u64);
+
+    transfer_token(
+        identifier_mode,
+        token_identifier,
+        owner,
+        target_owner_key,
+        caller,
+        false,
+        None,
+    )
+}
+
+// Moves an already authorized token from source_owner_key to target_owner_key: updates the owner,
+// balances and approval, emits the Transfer event carrying the memo, or the ForcedTransfer event
+// when the transfer was forced by the installer, and returns the receipt when the owner reverse
+// lookup is enabled.
+fn transfer_token(
+    identifier_mode: NFTIdentifierMode,
+    token_identifier: TokenIdentifier,
+    source_owner_key: Key,
+    target_owner_key: Key,
+    caller: Key,
+    forced: bool,
+    memo: Option<String>,
+) {
+    if NFTIdentifierMode::Hash == identifier_mode && runtime::get_key(OWNED_TOKENS).is_some() {
+        if utils::should_migrate_token_hashes(source_owner_key) {
+            utils::migrate_token_hashes(source_owner_key)
+        }
+
+        if utils::should_migrate_token_hashes(target_owner_key) {
+            utils::migrate_token_hashes(target_owner_key)
+        }
+    }
+
+    let target_owner_item_key = utils::encode_dictionary_item_key(target_owner_key);
+
+    // Updated token_owners dictionary. Revert if token_owner not found.
+    match utils::get_token_owner(&token_identifier) {
+        Some(token_actual_owner) => {
+            if token_actual_owner != source_owner_key {
+                runtime::revert(NFTCoreError::InvalidTokenOwner)
+            }
+            utils::upsert_dictionary_value_from_key(
+                TOKEN_OWNERS,
+                &token_identifier.get_dictionary_item_key(),
+                target_owner_key,
+            );
+        }
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    }
+
+    let source_owner_item_key = utils::encode_dictionary_item_key(source_owner_key);
+
+    // Update the from_account balance
+    let updated_from_account_balance =
+        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &source_owner_item_key) {
+            Some(balance) => {
+                if balance > 0u64 {
+                    balance - 1u64
+                } else {
+                    // This should never happen...
+                    runtime::revert(NFTCoreError::FatalTokenIdDuplication);
+                }
+            }
+            None => {
+                // This should never happen...
+                runtime::revert(NFTCoreError::FatalTokenIdDuplication);
+            }
+        };
+    utils::upsert_dictionary_value_from_key(
+        TOKEN_COUNT,
+        &source_owner_item_key,
+        updated_from_account_balance,
+    );
+    utils::update_number_of_holders(
+        updated_from_account_balance + 1u64,
+        updated_from_account_balance,
+    );
+
+    // Update the to_account balance
+    let updated_to_account_balance =
+        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &target_owner_item_key) {
+            Some(balance) => balance + 1u64,
+            None => 1u64,
+        };
+
+    utils::upsert_dictionary_value_from_key(
+        TOKEN_COUNT,
+        &target_owner_item_key,
+        updated_to_account_balance,
+    );
+    utils::update_number_of_holders(
+        updated_to_account_balance - 1u64,
+        updated_to_account_balance,
+    );
+
+    utils::upsert_dictionary_value_from_key(
+        APPROVED,
+        &token_identifier.get_dictionary_item_key(),
+        Option::<Key>::None,
+    );
+
+    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    ))
+    .unwrap_or_revert();
+
+    match (events_mode, forced) {
+        (EventsMode::NoEvents, _) => {}
+        (EventsMode::CEP47, true) => record_cep47_event_dictionary(CEP47Event::ForcedTransfer {
+            owner: source_owner_key,
+            sender: caller,
+            recipient: target_owner_key,
+            token_id: token_identifier.clone(),
+        }),
+        (EventsMode::CEP47, false) => record_cep47_event_dictionary(CEP47Event::Transfer {
+            sender: caller,
+            recipient: target_owner_key,
+            token_id: token_identifier.clone(),
+            memo,
+        }),
+        (EventsMode::CES, true) => casper_event_standard::emit(ForcedTransfer::new(
+            source_owner_key,
+            caller,
+            target_owner_key,
+            token_identifier.clone(),
+        )),
+        (EventsMode::CES, false) => {
+            // Emit Transfer event.
+            let spender = if caller == source_owner_key {
+                None
+            } else {
+                Some(caller)
+            };
+            casper_event_standard::emit(Transfer::new(
+                source_owner_key,
                 spender,
                 target_owner_key,
                 token_identifier.clone(),
+                memo,
             ));
         }
     }
@@ -1597,32 +2660,324 @@ pub extern "C" fn transfer() {
             .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
         runtime::ret(receipt)
     }
-}
+}
+
+// Returns the length of the Vec<String> in OWNED_TOKENS dictionary. If key is not found
+// it returns 0.
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TOKEN_OWNER,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    let owner_key_item_string = utils::encode_dictionary_item_key(owner_key);
+
+    let balance = utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owner_key_item_string)
+        .unwrap_or(0u64);
+
+    let balance_cl_value =
+        CLValue::from_t(balance).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(balance_cl_value);
+}
+
+// Returns the owner for a specified token identifier, throws error if token id is not valid
+#[no_mangle]
+pub extern "C" fn owner_of() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    let token_index_bound = utils::get_token_index_bound();
+
+    if let NFTIdentifierMode::Ordinal = identifier_mode {
+        // Revert if token_id is out of bounds
+        if token_identifier.get_index().unwrap_or_revert() >= token_index_bound {
+            runtime::revert(NFTCoreError::InvalidTokenIdentifier);
+        }
+    }
+
+    let token_owner = match utils::get_token_owner(&token_identifier) {
+        Some(token_owner) => token_owner,
+        None if utils::is_token_burned(&token_identifier) => {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+        }
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    let token_owner_cl_value =
+        CLValue::from_t(token_owner).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+
+    runtime::ret(token_owner_cl_value);
+}
+
+#[no_mangle]
+pub extern "C" fn metadata() {
+    let token_index_bound = utils::get_token_index_bound();
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    if let NFTIdentifierMode::Ordinal = identifier_mode {
+        // Revert if token_id is out of bounds
+        if token_identifier.get_index().unwrap_or_revert() >= token_index_bound {
+            runtime::revert(NFTCoreError::InvalidTokenIdentifier);
+        }
+    }
+
+    let metadata_kind_list: BTreeMap<NFTMetadataKind, Requirement> =
+        utils::get_stored_value_with_user_errors(
+            NFT_METADATA_KINDS,
+            NFTCoreError::MissingNFTMetadataKind,
+            NFTCoreError::InvalidNFTMetadataKind,
+        );
+
+    for (&metadata_kind, required) in metadata_kind_list.iter() {
+        match required {
+            &Requirement::Required => {
+                let metadata = if metadata_kind == NFTMetadataKind::Binary {
+                    // Binary metadata is returned base16 encoded, as in the token info.
+                    let metadata_bytes = utils::get_dictionary_value_from_key::<Bytes>(
+                        METADATA_BINARY,
+                        &token_identifier.get_dictionary_item_key(),
+                    )
+                    .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
+                    base16::encode_lower(metadata_bytes.as_slice())
+                } else if metadata_kind == NFTMetadataKind::BaseURI {
+                    // Revert if the token has not been minted.
+                    if utils::get_token_owner(&token_identifier).is_none() {
+                        if utils::is_token_burned(&token_identifier) {
+                            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+                        }
+                        runtime::revert(NFTCoreError::InvalidTokenIdentifier)
+                    }
+                    utils::get_base_token_uri(&token_identifier)
+                } else {
+                    utils::get_dictionary_value_from_key::<String>(
+                        &metadata::get_metadata_dictionary_name(&metadata_kind),
+                        &token_identifier.get_dictionary_item_key(),
+                    )
+                    .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier)
+                };
+                runtime::ret(
+                    CLValue::from_t(metadata)
+                        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+                );
+            }
+            _ => continue,
+        }
+    }
+    runtime::revert(NFTCoreError::MissingTokenMetaData)
+}
+
+// Returns the Binary metadata of a token as the bytes it was minted or updated with, where the
+// metadata entrypoint returns them base16 encoded.
+#[no_mangle]
+pub extern "C" fn metadata_bytes() {
+    let token_index_bound = utils::get_token_index_bound();
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    if let NFTIdentifierMode::Ordinal = identifier_mode {
+        // Revert if token_id is out of bounds
+        if token_identifier.get_index().unwrap_or_revert() >= token_index_bound {
+            runtime::revert(NFTCoreError::InvalidTokenIdentifier);
+        }
+    }
+
+    let metadata_kind_list: BTreeMap<NFTMetadataKind, Requirement> =
+        utils::get_stored_value_with_user_errors(
+            NFT_METADATA_KINDS,
+            NFTCoreError::MissingNFTMetadataKind,
+            NFTCoreError::InvalidNFTMetadataKind,
+        );
+
+    // Only collections with the Binary metadata kind store metadata as bytes.
+    if !metadata_kind_list.contains_key(&NFTMetadataKind::Binary) {
+        runtime::revert(NFTCoreError::MissingTokenMetaData)
+    }
+
+    let metadata_bytes = utils::get_dictionary_value_from_key::<Bytes>(
+        METADATA_BINARY,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
+
+    runtime::ret(
+        CLValue::from_t(metadata_bytes)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    );
+}
+
+// Returns the metadata of a token at the given revision along with the block time at which it was
+// set and the account or contract which set it. Revision 0 is the metadata the token was minted
+// with.
+#[no_mangle]
+pub extern "C" fn metadata_at() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    let revision = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_REVISION,
+        NFTCoreError::MissingRevision,
+        NFTCoreError::InvalidRevision,
+    )
+    .unwrap_or_revert();
+
+    let metadata_revision =
+        utils::get_dictionary_value_from_key::<(BTreeMap<u8, String>, u64, Key)>(
+            METADATA_HISTORY,
+            &utils::get_metadata_revision_item_key(&token_identifier, revision),
+        )
+        .unwrap_or_revert_with(NFTCoreError::MissingMetadataRevision);
+
+    runtime::ret(
+        CLValue::from_t(metadata_revision)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Returns the number of metadata revisions recorded for a token.
+#[no_mangle]
+pub extern "C" fn metadata_revision_count() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    let revision_count = utils::get_metadata_revision_count(&token_identifier);
+
+    runtime::ret(
+        CLValue::from_t(revision_count)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Returns the kinds of metadata a token was minted with.
+#[no_mangle]
+pub extern "C" fn token_metadata_kinds() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    // Revert if the token has not been minted, or if it was burnt and its storage reclaimed.
+    if utils::get_token_owner(&token_identifier).is_none() {
+        if utils::is_token_burned(&token_identifier) {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+        }
+        runtime::revert(NFTCoreError::InvalidTokenIdentifier)
+    }
+
+    let token_metadata_kinds = utils::get_token_metadata_kinds(&token_identifier);
+
+    runtime::ret(
+        CLValue::from_t(token_metadata_kinds)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Returns the index of a token from its token_hash, in the Hash identifier mode.
+#[no_mangle]
+pub extern "C" fn token_index_of() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+    if let NFTIdentifierMode::Ordinal = identifier_mode {
+        runtime::revert(NFTCoreError::InvalidIdentifierMode)
+    }
+
+    let token_hash: String = utils::get_named_arg_with_user_errors(
+        ARG_TOKEN_HASH,
+        NFTCoreError::MissingTokenID,
+        NFTCoreError::InvalidTokenIdentifier,
+    )
+    .unwrap_or_revert();
+
+    let token_index = utils::get_token_index(&TokenIdentifier::new_hash(token_hash));
+
+    runtime::ret(
+        CLValue::from_t(token_index).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Returns the hash of a token from its token_id index, in the Hash identifier mode.
+#[no_mangle]
+pub extern "C" fn token_hash_of() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+    if let NFTIdentifierMode::Ordinal = identifier_mode {
+        runtime::revert(NFTCoreError::InvalidIdentifierMode)
+    }
 
-// Returns the length of the Vec<String> in OWNED_TOKENS dictionary. If key is not found
-// it returns 0.
-#[no_mangle]
-pub extern "C" fn balance_of() {
-    let owner_key = utils::get_named_arg_with_user_errors::<Key>(
-        ARG_TOKEN_OWNER,
-        NFTCoreError::MissingAccountHash,
-        NFTCoreError::InvalidAccountHash,
+    let token_index: u64 = utils::get_named_arg_with_user_errors(
+        ARG_TOKEN_ID,
+        NFTCoreError::MissingTokenID,
+        NFTCoreError::InvalidTokenIdentifier,
     )
     .unwrap_or_revert();
 
-    let owner_key_item_string = utils::encode_dictionary_item_key(owner_key);
-
-    let balance = utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owner_key_item_string)
-        .unwrap_or(0u64);
+    let token_hash = utils::get_token_hash(token_index)
+        .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
 
-    let balance_cl_value =
-        CLValue::from_t(balance).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
-    runtime::ret(balance_cl_value);
+    runtime::ret(
+        CLValue::from_t(token_hash).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
 }
 
-// Returns the owner for a specified token identifier, throws error if token id is not valid
+// Returns the owner, issuer, approved spender, burnt flag, index and hash and the stored metadata
+// of a token at once.
 #[no_mangle]
-pub extern "C" fn owner_of() {
+pub extern "C" fn token_info() {
     let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
         IDENTIFIER_MODE,
         NFTCoreError::MissingIdentifierMode,
@@ -1632,42 +2987,64 @@ pub extern "C" fn owner_of() {
     .unwrap_or_revert();
 
     let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
 
-    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingNumberOfMintedTokens,
-        NFTCoreError::InvalidNumberOfMintedTokens,
-    );
-
-    if let NFTIdentifierMode::Ordinal = identifier_mode {
-        // Revert if token_id is out of bounds
-        if token_identifier.get_index().unwrap_or_revert() >= number_of_minted_tokens {
-            runtime::revert(NFTCoreError::InvalidTokenIdentifier);
+    // Revert if the token has not been minted, or if it was burnt and its storage reclaimed.
+    let owner = match utils::get_token_owner(&token_identifier) {
+        Some(owner) => owner,
+        None if utils::is_token_burned(&token_identifier) => {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
         }
+        None => runtime::revert(NFTCoreError::InvalidTokenIdentifier),
+    };
+    let issuer = utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_ISSUERS,
+        &token_identifier_dictionary_key,
+    )
+    .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
+    let approved = utils::get_dictionary_value_from_key::<Option<Key>>(
+        APPROVED,
+        &token_identifier_dictionary_key,
+    )
+    .flatten();
+
+    let (token_index, token_hash) = match &token_identifier {
+        // Ordinal tokens have no hash.
+        TokenIdentifier::Index(token_index) => (*token_index, None),
+        TokenIdentifier::Hash(token_hash) => (
+            utils::get_token_index(&token_identifier),
+            Some(token_hash.clone()),
+        ),
+    };
+
+    let mut metadata = utils::get_stored_token_metadata(&token_identifier);
+    if utils::get_token_metadata_kinds(&token_identifier)
+        .contains(&(NFTMetadataKind::BaseURI as u8))
+    {
+        metadata.insert(
+            NFTMetadataKind::BaseURI as u8,
+            utils::get_base_token_uri(&token_identifier),
+        );
     }
 
-    let token_owner = match utils::get_dictionary_value_from_key::<Key>(
-        TOKEN_OWNERS,
-        &token_identifier.get_dictionary_item_key(),
-    ) {
-        Some(token_owner) => token_owner,
-        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    let token_info = TokenInfo {
+        token_index,
+        token_hash,
+        owner,
+        issuer,
+        approved,
+        burnt: utils::is_token_burned(&token_identifier),
+        metadata,
     };
 
-    let token_owner_cl_value =
-        CLValue::from_t(token_owner).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
-
-    runtime::ret(token_owner_cl_value);
+    runtime::ret(
+        CLValue::from_t(token_info).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
 }
 
+// Returns the account or contract which minted a token.
 #[no_mangle]
-pub extern "C" fn metadata() {
-    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingNumberOfMintedTokens,
-        NFTCoreError::InvalidNumberOfMintedTokens,
-    );
-
+pub extern "C" fn issuer_of() {
     let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
         IDENTIFIER_MODE,
         NFTCoreError::MissingIdentifierMode,
@@ -1678,37 +3055,116 @@ pub extern "C" fn metadata() {
 
     let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
 
-    if let NFTIdentifierMode::Ordinal = identifier_mode {
-        // Revert if token_id is out of bounds
-        if token_identifier.get_index().unwrap_or_revert() >= number_of_minted_tokens {
-            runtime::revert(NFTCoreError::InvalidTokenIdentifier);
-        }
-    }
+    let issuer = utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_ISSUERS,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
 
-    let metadata_kind_list: BTreeMap<NFTMetadataKind, Requirement> =
-        utils::get_stored_value_with_user_errors(
-            NFT_METADATA_KINDS,
-            NFTCoreError::MissingNFTMetadataKind,
-            NFTCoreError::InvalidNFTMetadataKind,
-        );
+    runtime::ret(
+        CLValue::from_t(issuer).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
 
-    for (&metadata_kind, required) in metadata_kind_list.iter() {
-        match required {
-            &Requirement::Required => {
-                let metadata = utils::get_dictionary_value_from_key::<String>(
-                    &metadata::get_metadata_dictionary_name(&metadata_kind),
-                    &token_identifier.get_dictionary_item_key(),
-                )
-                .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
-                runtime::ret(
-                    CLValue::from_t(metadata)
-                        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
-                );
-            }
-            _ => continue,
-        }
-    }
-    runtime::revert(NFTCoreError::MissingTokenMetaData)
+// Returns a page of the identifiers of the tokens minted by an account or contract, in the order
+// they were minted.
+#[no_mangle]
+pub extern "C" fn tokens_issued_by() {
+    let issuer = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_ISSUER,
+        NFTCoreError::MissingIssuer,
+        NFTCoreError::InvalidIssuer,
+    )
+    .unwrap_or_revert();
+    let page_start = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_PAGE_START,
+        NFTCoreError::MissingIssuedTokensPage,
+        NFTCoreError::InvalidIssuedTokensPage,
+    )
+    .unwrap_or_revert();
+    let page_size = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_PAGE_SIZE,
+        NFTCoreError::MissingIssuedTokensPage,
+        NFTCoreError::InvalidIssuedTokensPage,
+    )
+    .unwrap_or_revert();
+
+    let issued_tokens = utils::get_tokens_issued_by(issuer, page_start, page_size);
+
+    runtime::ret(
+        CLValue::from_t(issued_tokens)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Returns the total token supply of the contract.
+#[no_mangle]
+pub extern "C" fn total_token_supply() {
+    let total_token_supply = utils::get_stored_value_with_user_errors::<u64>(
+        TOTAL_TOKEN_SUPPLY,
+        NFTCoreError::MissingTotalTokenSupply,
+        NFTCoreError::InvalidTotalTokenSupply,
+    );
+    runtime::ret(
+        CLValue::from_t(total_token_supply)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Returns the number of tokens minted by the contract, burnt tokens included.
+#[no_mangle]
+pub extern "C" fn number_of_minted_tokens() {
+    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingNumberOfMintedTokens,
+        NFTCoreError::InvalidNumberOfMintedTokens,
+    );
+    runtime::ret(
+        CLValue::from_t(number_of_minted_tokens)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Returns the number of tokens burnt.
+#[no_mangle]
+pub extern "C" fn number_of_burnt_tokens() {
+    let number_of_burnt_tokens = utils::get_supply_statistic(
+        NUMBER_OF_BURNT_TOKENS,
+        NFTCoreError::InvalidNumberOfBurntTokens,
+    );
+    runtime::ret(
+        CLValue::from_t(number_of_burnt_tokens)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Returns the number of minted tokens which have not been burnt.
+#[no_mangle]
+pub extern "C" fn circulating_supply() {
+    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingNumberOfMintedTokens,
+        NFTCoreError::InvalidNumberOfMintedTokens,
+    );
+    let number_of_burnt_tokens = utils::get_supply_statistic(
+        NUMBER_OF_BURNT_TOKENS,
+        NFTCoreError::InvalidNumberOfBurntTokens,
+    );
+    runtime::ret(
+        CLValue::from_t(number_of_minted_tokens - number_of_burnt_tokens)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
+}
+
+// Returns the number of distinct owners holding at least one token.
+#[no_mangle]
+pub extern "C" fn number_of_holders() {
+    let number_of_holders =
+        utils::get_supply_statistic(NUMBER_OF_HOLDERS, NFTCoreError::InvalidNumberOfHolders);
+    runtime::ret(
+        CLValue::from_t(number_of_holders)
+            .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    )
 }
 
 // Returns approved account hash for a specified token identifier, throws error if token id is not
@@ -1726,15 +3182,11 @@ pub extern "C" fn get_approved() {
     let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
 
     // Revert if token_id is out of bounds.
-    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingNumberOfMintedTokens,
-        NFTCoreError::InvalidNumberOfMintedTokens,
-    );
+    let token_index_bound = utils::get_token_index_bound();
 
     if let NFTIdentifierMode::Ordinal = identifier_mode {
         // Revert if token_id is out of bounds
-        if token_identifier.get_index().unwrap_or_revert() >= number_of_minted_tokens {
+        if token_identifier.get_index().unwrap_or_revert() >= token_index_bound {
             runtime::revert(NFTCoreError::InvalidTokenIdentifier);
         }
     }
@@ -1758,6 +3210,65 @@ pub extern "C" fn get_approved() {
     runtime::ret(approved_cl_value);
 }
 
+// Whether the caller can update the metadata of a token it does not own in the Delegated metadata
+// update mode.
+fn is_delegated_metadata_updater(
+    token_identifier: &TokenIdentifier,
+    token_owner: &Key,
+    caller: &Key,
+    contract_package: Option<Key>,
+) -> bool {
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+
+    // Check if caller is the metadata editor
+    if utils::get_metadata_editor() == Some(*caller) {
+        return true;
+    }
+
+    // Check if caller has minted the token
+    if utils::get_dictionary_value_from_key::<Key>(TOKEN_ISSUERS, &token_identifier_dictionary_key)
+        == Some(*caller)
+    {
+        return true;
+    }
+
+    // Check if caller is approved for the token
+    if let Some(Some(approved)) = utils::get_dictionary_value_from_key::<Option<Key>>(
+        APPROVED,
+        &token_identifier_dictionary_key,
+    ) {
+        if approved == *caller {
+            return true;
+        }
+    }
+
+    // Check if caller is operator for the owner
+    let owner_operator_item_key = utils::encode_key_and_value(token_owner, caller);
+    if utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
+        .unwrap_or_default()
+    {
+        return true;
+    }
+
+    // With operator package mode check if caller's package is operator for the owner
+    match (
+        utils::get_stored_value_with_user_errors::<bool>(
+            PACKAGE_OPERATOR_MODE,
+            NFTCoreError::MissingPackageOperatorMode,
+            NFTCoreError::InvalidPackageOperatorMode,
+        ),
+        contract_package,
+    ) {
+        (true, Some(contract_package)) => {
+            let owner_operator_item_key =
+                utils::encode_key_and_value(token_owner, &contract_package);
+            utils::get_dictionary_value_from_key::<bool>(OPERATORS, &owner_operator_item_key)
+                .unwrap_or_default()
+        }
+        _ => false,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn set_token_metadata() {
     let metadata_mutability: MetadataMutability = utils::get_stored_value_with_user_errors::<u8>(
@@ -1782,21 +3293,39 @@ pub extern "C" fn set_token_metadata() {
 
     let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
 
-    let token_owner = utils::get_dictionary_value_from_key::<Key>(
-        TOKEN_OWNERS,
-        &token_identifier.get_dictionary_item_key(),
-    );
+    let token_owner_key = match utils::get_token_owner(&token_identifier) {
+        Some(token_owner_key) => token_owner_key,
+        None if utils::is_token_burned(&token_identifier) => {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+        }
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
 
-    if let Some(token_owner_key) = token_owner {
-        let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
-            Caller::Session(account_hash) => account_hash.into(),
-            Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
+    let (caller, contract_package): (Key, Option<Key>) =
+        match utils::get_verified_caller().unwrap_or_revert() {
+            Caller::Session(account_hash) => (account_hash.into(), None),
+            Caller::StoredCaller(contract_hash, contract_package_hash) => {
+                (contract_hash.into(), Some(contract_package_hash.into()))
+            }
         };
-        if caller != token_owner_key {
-            runtime::revert(NFTCoreError::InvalidTokenOwner)
-        }
-    } else {
-        runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey)
+
+    // Revert if caller is not the token owner nor, in the Delegated metadata update mode, the
+    // token issuer, the approved spender, an operator for the owner or the metadata editor.
+    if caller != token_owner_key
+        && (utils::get_metadata_update_mode() == MetadataUpdateMode::OwnerOnly
+            || !is_delegated_metadata_updater(
+                &token_identifier,
+                &token_owner_key,
+                &caller,
+                contract_package,
+            ))
+    {
+        runtime::revert(NFTCoreError::InvalidTokenOwner)
+    }
+
+    // Revert if the metadata of this token has been frozen.
+    if utils::is_metadata_frozen(&token_identifier) {
+        runtime::revert(NFTCoreError::FrozenMetadata)
     }
 
     let metadata_kinds: BTreeMap<NFTMetadataKind, Requirement> =
@@ -1806,6 +3335,8 @@ pub extern "C" fn set_token_metadata() {
             NFTCoreError::InvalidNFTMetadataKind,
         );
 
+    utils::check_token_metadata_size();
+
     let updated_token_metadata: String = utils::get_named_arg_with_user_errors(
         ARG_TOKEN_META_DATA,
         NFTCoreError::MissingTokenMetaData,
@@ -1813,19 +3344,64 @@ pub extern "C" fn set_token_metadata() {
     )
     .unwrap_or_revert();
 
+    let updated_token_metadata_bytes: Bytes = utils::get_optional_named_arg_with_user_errors(
+        ARG_TOKEN_META_DATA_BYTES,
+        NFTCoreError::InvalidTokenMetaData,
+    )
+    .unwrap_or_default();
+
+    // Only the kinds the token was minted with are updated.
+    let metadata_kinds = utils::get_token_metadata_requirements(
+        metadata_kinds,
+        utils::get_token_metadata_kinds_selection(&token_identifier),
+    );
+
     for (metadata_kind, required) in metadata_kinds {
-        if required == Requirement::Unneeded {
+        // Token uris of the BaseURI kind are derived from the base uri rather than stored.
+        if required == Requirement::Unneeded || metadata_kind == NFTMetadataKind::BaseURI {
+            continue;
+        }
+        if metadata_kind == NFTMetadataKind::Binary {
+            match metadata::validate_binary_metadata(&updated_token_metadata_bytes) {
+                Ok(()) => utils::upsert_dictionary_value_from_key(
+                    METADATA_BINARY,
+                    &token_identifier.get_dictionary_item_key(),
+                    updated_token_metadata_bytes.clone(),
+                ),
+                Err(err) if required == Requirement::Required => runtime::revert(err),
+                Err(_) => {}
+            }
             continue;
         }
         let token_metadata_validation =
             metadata::validate_metadata(&metadata_kind, updated_token_metadata.clone());
         match token_metadata_validation {
             Ok(validated_token_metadata) => {
+                // Revert if the update changes a field locked by the metadata schema.
+                if let Some(current_token_metadata) = utils::get_dictionary_value_from_key::<String>(
+                    &metadata::get_metadata_dictionary_name(&metadata_kind),
+                    &token_identifier.get_dictionary_item_key(),
+                ) {
+                    metadata::validate_metadata_update(
+                        &metadata_kind,
+                        &current_token_metadata,
+                        &validated_token_metadata,
+                    )
+                    .unwrap_or_revert();
+                }
                 utils::upsert_dictionary_value_from_key(
                     &metadata::get_metadata_dictionary_name(&metadata_kind),
                     &token_identifier.get_dictionary_item_key(),
                     validated_token_metadata,
                 );
+                // Record the json schema version the custom metadata was validated against.
+                if metadata_kind == NFTMetadataKind::CustomValidated {
+                    utils::upsert_dictionary_value_from_key(
+                        TOKEN_SCHEMA_VERSIONS,
+                        &token_identifier.get_dictionary_item_key(),
+                        Some(utils::get_json_schema_version()),
+                    );
+                }
             }
             Err(err) => {
                 if required == Requirement::Required {
@@ -1835,6 +3411,8 @@ pub extern "C" fn set_token_metadata() {
         }
     }
 
+    utils::record_metadata_revision(&token_identifier, caller);
+
     let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
         EVENTS_MODE,
         NFTCoreError::MissingEventsMode,
@@ -1872,6 +3450,10 @@ fn update_token_supply() -> (u64, u64) {
             {
                 runtime::revert(NFTCoreError::CannotUpgradeToMoreSupply)
             }
+            // Shuffled indices are drawn from a pool spanning the total token supply.
+            if utils::get_ordinal_assignment_mode() == OrdinalAssignmentMode::Shuffled {
+                runtime::revert(NFTCoreError::CannotUpgradeShuffledSupply)
+            }
 
             let total_token_supply_uref = utils::get_uref(
                 ARG_TOTAL_TOKEN_SUPPLY,
@@ -1960,6 +3542,71 @@ fn do_migration() {
     }
 }
 
+// Permanently locks the metadata of a token, even when the metadata mutability is Mutable. Only the
+// token owner or the installer can freeze the metadata of a token.
+#[no_mangle]
+pub extern "C" fn freeze_metadata() {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+
+    if utils::is_token_burned(&token_identifier) {
+        runtime::revert(NFTCoreError::PreviouslyBurntToken)
+    }
+
+    let token_owner_key = match utils::get_token_owner(&token_identifier) {
+        Some(token_owner_key) => token_owner_key,
+        None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+    };
+
+    let caller: Key = match utils::get_verified_caller().unwrap_or_revert() {
+        Caller::Session(account_hash) => account_hash.into(),
+        Caller::StoredCaller(contract_hash, _) => contract_hash.into(),
+    };
+
+    let installer = utils::get_account_hash(
+        INSTALLER,
+        NFTCoreError::MissingInstaller,
+        NFTCoreError::InvalidInstaller,
+    );
+
+    if caller != token_owner_key && caller != Key::Account(installer) {
+        runtime::revert(NFTCoreError::InvalidTokenOwner)
+    }
+
+    if utils::is_metadata_frozen(&token_identifier) {
+        runtime::revert(NFTCoreError::FrozenMetadata)
+    }
+
+    utils::upsert_dictionary_value_from_key(
+        FROZEN_METADATA,
+        &token_identifier.get_dictionary_item_key(),
+        true,
+    );
+
+    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    ))
+    .unwrap_or_revert();
+
+    // Emit MetadataFrozen event.
+    match events_mode {
+        EventsMode::NoEvents => {}
+        EventsMode::CES => casper_event_standard::emit(MetadataFrozen::new(token_identifier)),
+        EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::MetadataFrozen {
+            token_id: token_identifier,
+        }),
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn migrate() {
     let reporting_mode = if runtime::get_key(REPORTING_MODE).is_some() {
@@ -2008,7 +3655,10 @@ pub extern "C" fn migrate() {
             .try_into()
             .unwrap_or_revert_with(NFTCoreError::InvalidEventsMode);
         match (current_events_mode, requested_events_mode) {
-            (EventsMode::CES, EventsMode::CES) => casper_event_standard::emit(Migration::new()),
+            (EventsMode::CES, EventsMode::CES) => {
+                utils::update_events_schemas();
+                casper_event_standard::emit(Migration::new());
+            }
             (_, EventsMode::CES) => {
                 // Initialize events structures.
                 utils::init_events();
@@ -2021,7 +3671,10 @@ pub extern "C" fn migrate() {
     } else {
         match current_events_mode {
             EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Migrate),
-            EventsMode::CES => casper_event_standard::emit(Migration::new()),
+            EventsMode::CES => {
+                utils::update_events_schemas();
+                casper_event_standard::emit(Migration::new());
+            }
             _ => {
                 // Store "no events" mode in case it was never stored like version < 1.2
                 if !runtime::has_key(EVENTS_MODE) {
@@ -2092,6 +3745,96 @@ pub extern "C" fn migrate() {
         storage::new_dictionary(OPERATORS)
             .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     }
+    // Add NONCES dict used by transfer_by_signature
+    if runtime::get_key(NONCES).is_none() {
+        storage::new_dictionary(NONCES)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add json schema versioning, the current schema becoming the first version
+    if runtime::get_key(JSON_SCHEMA_VERSION).is_none() {
+        let json_schema = utils::get_stored_value_with_user_errors::<String>(
+            JSON_SCHEMA,
+            NFTCoreError::MissingJsonSchema,
+            NFTCoreError::InvalidJsonSchema,
+        );
+        runtime::put_key(JSON_SCHEMA_VERSION, storage::new_uref(0u32).into());
+        storage::new_dictionary(JSON_SCHEMAS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        storage::new_dictionary(TOKEN_SCHEMA_VERSIONS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        utils::upsert_dictionary_value_from_key(JSON_SCHEMAS, &0u32.to_string(), json_schema);
+    }
+    // Add FROZEN_METADATA dict used by freeze_metadata
+    if runtime::get_key(FROZEN_METADATA).is_none() {
+        storage::new_dictionary(FROZEN_METADATA)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add an empty base uri
+    if runtime::get_key(BASE_URI).is_none() {
+        runtime::put_key(BASE_URI, storage::new_uref(String::new()).into());
+    }
+    // Add the binary metadata dict along with an empty layout
+    if runtime::get_key(METADATA_BINARY).is_none() {
+        storage::new_dictionary(METADATA_BINARY)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        runtime::put_key(
+            BINARY_METADATA_LAYOUT,
+            storage::new_uref(Vec::<u8>::new()).into(),
+        );
+    }
+    // Add TOKEN_METADATA_KINDS dict, tokens minted before have the required kinds
+    if runtime::get_key(TOKEN_METADATA_KINDS).is_none() {
+        storage::new_dictionary(TOKEN_METADATA_KINDS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add an empty collection metadata
+    if runtime::get_key(COLLECTION_METADATA).is_none() {
+        let mut collection_metadata: BTreeMap<String, String> = BTreeMap::new();
+        utils::set_collection_metadata_from_runtime_args(&mut collection_metadata);
+        runtime::put_key(
+            COLLECTION_METADATA,
+            storage::new_uref(collection_metadata).into(),
+        );
+    }
+    // Add the per-issuer token index, tokens minted before are not listed
+    if runtime::get_key(ISSUED_TOKENS).is_none() {
+        storage::new_dictionary(ISSUED_TOKENS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        storage::new_dictionary(ISSUED_TOKEN_COUNTS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        storage::new_dictionary(ISSUED_TOKEN_RECORDS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add metadata history dicts, the history of existing tokens starts at their next update
+    if runtime::get_key(METADATA_HISTORY).is_none() {
+        storage::new_dictionary(METADATA_HISTORY)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+        storage::new_dictionary(METADATA_REVISION_COUNTS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Add the supply statistics, counting the burnt tokens and the holders of the tokens minted
+    // before. They are left unavailable until a later upgrade if some tokens cannot be enumerated.
+    if runtime::get_key(NUMBER_OF_BURNT_TOKENS).is_none() {
+        let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+            IDENTIFIER_MODE,
+            NFTCoreError::MissingIdentifierMode,
+            NFTCoreError::InvalidIdentifierMode,
+        )
+        .try_into()
+        .unwrap_or_revert();
+        if let Some((number_of_burnt_tokens, number_of_holders)) =
+            utils::count_supply_statistics(&identifier_mode)
+        {
+            runtime::put_key(
+                NUMBER_OF_BURNT_TOKENS,
+                storage::new_uref(number_of_burnt_tokens).into(),
+            );
+            runtime::put_key(
+                NUMBER_OF_HOLDERS,
+                storage::new_uref(number_of_holders).into(),
+            );
+        }
+    }
 
     utils::migrate_contract_whitelist_to_acl_whitelist();
 }
@@ -2217,6 +3960,10 @@ fn generate_entry_points() -> EntryPoints {
         vec![
             Parameter::new(ARG_COLLECTION_NAME, CLType::String),
             Parameter::new(ARG_COLLECTION_SYMBOL, CLType::String),
+            Parameter::new(ARG_CONTRACT_URI, CLType::String),
+            Parameter::new(ARG_COLLECTION_DESCRIPTION, CLType::String),
+            Parameter::new(ARG_COLLECTION_IMAGE, CLType::String),
+            Parameter::new(ARG_COLLECTION_EXTERNAL_LINK, CLType::String),
             Parameter::new(ARG_TOTAL_TOKEN_SUPPLY, CLType::U64),
             Parameter::new(ARG_ALLOW_MINTING, CLType::Bool),
             Parameter::new(ARG_MINTING_MODE, CLType::U8),
@@ -2228,18 +3975,48 @@ fn generate_entry_points() -> EntryPoints {
             Parameter::new(ARG_ACL_PACKAGE_MODE, CLType::Bool),
             Parameter::new(ARG_PACKAGE_OPERATOR_MODE, CLType::Bool),
             Parameter::new(ARG_JSON_SCHEMA, CLType::String),
+            Parameter::new(ARG_BASE_URI, CLType::String),
             Parameter::new(ARG_RECEIPT_NAME, CLType::String),
             Parameter::new(ARG_IDENTIFIER_MODE, CLType::U8),
             Parameter::new(ARG_BURN_MODE, CLType::U8),
             Parameter::new(ARG_OPERATOR_BURN_MODE, CLType::Bool),
             Parameter::new(ARG_NFT_METADATA_KIND, CLType::U8),
             Parameter::new(ARG_METADATA_MUTABILITY, CLType::U8),
+            Parameter::new(ARG_METADATA_UPDATE_MODE, CLType::U8),
+            Parameter::new(ARG_METADATA_EDITOR, CLType::Option(Box::new(CLType::Key))),
             Parameter::new(ARG_OWNER_LOOKUP_MODE, CLType::U8),
             Parameter::new(ARG_EVENTS_MODE, CLType::U8),
             Parameter::new(
                 ARG_TRANSFER_FILTER_CONTRACT,
                 CLType::Option(Box::new(CLType::Key)),
             ),
+            Parameter::new(
+                ARG_LOCKED_METADATA_FIELDS,
+                CLType::List(Box::new(CLType::String)),
+            ),
+            Parameter::new(ARG_STRICT_METADATA_VALIDATION, CLType::Bool),
+            Parameter::new(
+                ARG_ALLOWED_URI_SCHEMES,
+                CLType::List(Box::new(CLType::String)),
+            ),
+            Parameter::new(ARG_MAX_METADATA_SIZE, CLType::U64),
+            Parameter::new(ARG_METADATA_STORAGE_FORMAT, CLType::U8),
+            Parameter::new(
+                ARG_BINARY_METADATA_LAYOUT,
+                CLType::List(Box::new(CLType::U8)),
+            ),
+            Parameter::new(ARG_REVEAL_MODE, CLType::U8),
+            Parameter::new(ARG_PROVENANCE_HASH, CLType::String),
+            Parameter::new(ARG_PLACEHOLDER_URI, CLType::String),
+            Parameter::new(ARG_ORDINAL_ASSIGNMENT_MODE, CLType::U8),
+            Parameter::new(ARG_SHUFFLE_SEED, CLType::String),
+            Parameter::new(ARG_HASH_IDENTIFIER_MODE, CLType::U8),
+            Parameter::new(ARG_IDENTIFIER_CHARSET, CLType::String),
+            Parameter::new(ARG_IDENTIFIER_MIN_LENGTH, CLType::U32),
+            Parameter::new(ARG_IDENTIFIER_MAX_LENGTH, CLType::U32),
+            Parameter::new(ARG_IDENTIFIER_PREFIX, CLType::String),
+            Parameter::new(ARG_BURN_STORAGE_MODE, CLType::U8),
+            Parameter::new(ARG_CLAWBACK_MODE, CLType::U8),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -2268,12 +4045,41 @@ fn generate_entry_points() -> EntryPoints {
             Parameter::new(ARG_ACL_PACKAGE_MODE, CLType::Bool),
             Parameter::new(ARG_PACKAGE_OPERATOR_MODE, CLType::Bool),
             Parameter::new(ARG_OPERATOR_BURN_MODE, CLType::Bool),
+            Parameter::new(ARG_METADATA_EDITOR, CLType::Option(Box::new(CLType::Key))),
+            Parameter::new(ARG_CONTRACT_URI, CLType::String),
+            Parameter::new(ARG_COLLECTION_DESCRIPTION, CLType::String),
+            Parameter::new(ARG_COLLECTION_IMAGE, CLType::String),
+            Parameter::new(ARG_COLLECTION_EXTERNAL_LINK, CLType::String),
+            Parameter::new(ARG_BASE_URI, CLType::String),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
 
+    // This entrypoint publishes a new version of the json schema validating CustomValidated
+    // metadata. Meant to be called by the managing account (INSTALLER) post installation.
+    // Previous versions are kept, and tokens record the version their metadata was validated
+    // against. Reverts with InvalidJsonSchema if the schema cannot be parsed.
+    let set_json_schema = EntryPoint::new(
+        ENTRY_POINT_SET_JSON_SCHEMA,
+        vec![Parameter::new(ARG_JSON_SCHEMA, CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint reveals the base uri committed to by the provenance hash at installation
+    // in the Delayed reveal mode. Meant to be called by the managing account (INSTALLER) once.
+    // Reverts with ProvenanceHashMismatch if the blake2b hash of the base uri does not match.
+    let reveal = EntryPoint::new(
+        ENTRY_POINT_REVEAL,
+        vec![Parameter::new(ARG_BASE_URI, CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint mints a new token with provided metadata.
     // Meant to be called post installation.
     // Reverts with MintingIsPaused error if allow_minting is false.
@@ -2284,7 +4090,8 @@ fn generate_entry_points() -> EntryPoints {
     // TokenSupplyDepleted. The mint entrypoint also checks whether the calling account
     // is the managing account (the installer) If not, and if public_minting is set to
     // false, it reverts with the error InvalidAccount. The newly minted token is
-    // automatically assigned a U64 ID equal to the current number_of_minted_tokens. The
+    // automatically assigned a U64 ID equal to the current number_of_minted_tokens, or drawn
+    // from the IDs not minted yet in the Shuffled ordinal assignment mode. The
     // account is listed as the token owner, as well as added to the accounts list of owned
     // tokens. After minting is successful the number_of_minted_tokens is incremented by
     // one.
@@ -2332,6 +4139,45 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint burns a token without the consent of its owner. Reverts unless the contract
+    // was installed with the Enabled clawback mode and the caller is the installer.
+    let force_burn = EntryPoint::new(
+        ENTRY_POINT_FORCE_BURN,
+        vec![], // <- either HASH or INDEX
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint transfers a token to the target key without the consent of its owner.
+    // Reverts unless the contract was installed with the Enabled clawback mode and the caller is
+    // the installer.
+    let force_transfer = EntryPoint::new(
+        ENTRY_POINT_FORCE_TRANSFER,
+        vec![Parameter::new(ARG_TARGET_KEY, CLType::Key)],
+        CLType::Tuple2([Box::new(CLType::String), Box::new(CLType::Key)]),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint transfers ownership of a token on behalf of its owner, who authorizes the
+    // transfer off-chain by signing the token, target, nonce and deadline. The caller (relayer)
+    // pays for the deploy. Reverts if the signature is invalid, the deadline has passed, the
+    // nonce does not match the owner's current nonce or the signer is not the token owner.
+    let transfer_by_signature = EntryPoint::new(
+        ENTRY_POINT_TRANSFER_BY_SIGNATURE,
+        vec![
+            Parameter::new(ARG_TARGET_KEY, CLType::Key),
+            Parameter::new(ARG_NONCE, CLType::U64),
+            Parameter::new(ARG_DEADLINE, CLType::U64),
+            Parameter::new(ARG_PUBLIC_KEY, CLType::PublicKey),
+            Parameter::new(ARG_SIGNATURE, CLType::List(Box::new(CLType::U8))),
+        ],
+        CLType::Tuple2([Box::new(CLType::String), Box::new(CLType::Key)]),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint approves another token holder (an approved account) to transfer tokens. It
     // reverts if token_id is invalid, if caller is not the owner nor operator, if token has already
     // been burnt, or if caller tries to approve themselves as an approved account.
@@ -2409,6 +4255,19 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint returns the collection metadata: the contract_uri, description, image and
+    // external_link of the collection.
+    let collection_metadata = EntryPoint::new(
+        ENTRY_POINT_COLLECTION_METADATA,
+        vec![],
+        CLType::Map {
+            key: Box::new(CLType::String),
+            value: Box::new(CLType::String),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint returns the metadata associated with the provided token_id
     let metadata = EntryPoint::new(
         ENTRY_POINT_METADATA,
@@ -2418,6 +4277,153 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint returns the Binary metadata associated with the provided token_id as bytes.
+    // Reverts if the collection does not use the Binary metadata kind.
+    let metadata_bytes = EntryPoint::new(
+        ENTRY_POINT_METADATA_BYTES,
+        vec![], // <- either HASH or INDEX
+        CLType::List(Box::new(CLType::U8)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the stored metadata associated with the provided token_id at the
+    // provided revision by metadata kind, with the block time at which it was set and the account
+    // or contract which set it.
+    // Reverts if the revision has not been recorded.
+    let metadata_at = EntryPoint::new(
+        ENTRY_POINT_METADATA_AT,
+        vec![Parameter::new(ARG_REVISION, CLType::U64)],
+        CLType::Tuple3([
+            Box::new(CLType::Map {
+                key: Box::new(CLType::U8),
+                value: Box::new(CLType::String),
+            }),
+            Box::new(CLType::U64),
+            Box::new(CLType::Key),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the number of metadata revisions recorded for the provided token_id
+    let metadata_revision_count = EntryPoint::new(
+        ENTRY_POINT_METADATA_REVISION_COUNT,
+        vec![], // <- either HASH or INDEX
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the kinds of metadata the provided token_id was minted with
+    let token_metadata_kinds = EntryPoint::new(
+        ENTRY_POINT_TOKEN_METADATA_KINDS,
+        vec![], // <- either HASH or INDEX
+        CLType::List(Box::new(CLType::U8)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the index of the token identified by the provided token_hash.
+    // Reverts with InvalidTokenIdentifier if no token was minted with the hash, and with
+    // InvalidIdentifierMode in the Ordinal identifier mode.
+    let token_index_of = EntryPoint::new(
+        ENTRY_POINT_TOKEN_INDEX_OF,
+        vec![Parameter::new(ARG_TOKEN_HASH, CLType::String)],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the hash of the token identified by the provided token_id index.
+    // Reverts with InvalidTokenIdentifier if no token was minted at the index, and with
+    // InvalidIdentifierMode in the Ordinal identifier mode.
+    let token_hash_of = EntryPoint::new(
+        ENTRY_POINT_TOKEN_HASH_OF,
+        vec![Parameter::new(ARG_TOKEN_ID, CLType::U64)],
+        CLType::String,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the TokenInfo of the token identified by the provided token_id or
+    // token_hash.
+    let token_info = EntryPoint::new(
+        ENTRY_POINT_TOKEN_INFO,
+        vec![], // <- either HASH or INDEX
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns the account or contract which minted the token identified by the
+    // provided token_id or token_hash.
+    let issuer_of = EntryPoint::new(
+        ENTRY_POINT_ISSUER_OF,
+        vec![], // <- either HASH or INDEX
+        CLType::Key,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint returns up to page_size identifiers of the tokens minted by the issuer,
+    // starting at page_start. Reverts with InvalidIssuedTokensPage if page_size is zero or above
+    // the maximum page size.
+    let tokens_issued_by = EntryPoint::new(
+        ENTRY_POINT_TOKENS_ISSUED_BY,
+        vec![
+            Parameter::new(ARG_ISSUER, CLType::Key),
+            Parameter::new(ARG_PAGE_START, CLType::U64),
+            Parameter::new(ARG_PAGE_SIZE, CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::String)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // These entrypoints return the supply statistics of the contract. The statistics on burnt
+    // tokens and holders revert with their Missing error on contracts upgraded from a version
+    // which did not keep them.
+    let total_token_supply = EntryPoint::new(
+        ENTRY_POINT_TOTAL_TOKEN_SUPPLY,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let number_of_minted_tokens = EntryPoint::new(
+        ENTRY_POINT_NUMBER_OF_MINTED_TOKENS,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let number_of_burnt_tokens = EntryPoint::new(
+        ENTRY_POINT_NUMBER_OF_BURNT_TOKENS,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let circulating_supply = EntryPoint::new(
+        ENTRY_POINT_CIRCULATING_SUPPLY,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let number_of_holders = EntryPoint::new(
+        ENTRY_POINT_NUMBER_OF_HOLDERS,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint updates the metadata if valid.
     let set_token_metadata = EntryPoint::new(
         ENTRY_POINT_SET_TOKEN_METADATA,
@@ -2427,6 +4433,16 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint permanently locks the metadata of a token, after which set_token_metadata
+    // reverts for it. Only the token owner or the installer can freeze the metadata of a token.
+    let freeze_metadata = EntryPoint::new(
+        ENTRY_POINT_FREEZE_METADATA,
+        vec![], // <- either HASH or INDEX
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint will upgrade the contract from the 1_0 version to the
     // 1_1 version. The contract will insert any addition dictionaries and
     // sentinel values that were absent in the previous version of the contract.
@@ -2471,18 +4487,39 @@ fn generate_entry_points() -> EntryPoints {
 
     entry_points.add_entry_point(init_contract);
     entry_points.add_entry_point(set_variables);
+    entry_points.add_entry_point(set_json_schema);
+    entry_points.add_entry_point(reveal);
     entry_points.add_entry_point(mint);
     entry_points.add_entry_point(burn);
     entry_points.add_entry_point(transfer);
+    entry_points.add_entry_point(transfer_by_signature);
+    entry_points.add_entry_point(force_burn);
+    entry_points.add_entry_point(force_transfer);
     entry_points.add_entry_point(approve);
     entry_points.add_entry_point(revoke);
     entry_points.add_entry_point(owner_of);
     entry_points.add_entry_point(balance_of);
     entry_points.add_entry_point(get_approved);
+    entry_points.add_entry_point(collection_metadata);
     entry_points.add_entry_point(metadata);
+    entry_points.add_entry_point(metadata_bytes);
+    entry_points.add_entry_point(metadata_at);
+    entry_points.add_entry_point(metadata_revision_count);
+    entry_points.add_entry_point(token_metadata_kinds);
+    entry_points.add_entry_point(token_index_of);
+    entry_points.add_entry_point(token_hash_of);
+    entry_points.add_entry_point(token_info);
+    entry_points.add_entry_point(issuer_of);
+    entry_points.add_entry_point(tokens_issued_by);
+    entry_points.add_entry_point(total_token_supply);
+    entry_points.add_entry_point(number_of_minted_tokens);
+    entry_points.add_entry_point(number_of_burnt_tokens);
+    entry_points.add_entry_point(circulating_supply);
+    entry_points.add_entry_point(number_of_holders);
     entry_points.add_entry_point(set_approval_for_all);
     entry_points.add_entry_point(is_approved_for_all);
     entry_points.add_entry_point(set_token_metadata);
+    entry_points.add_entry_point(freeze_metadata);
     entry_points.add_entry_point(migrate);
     entry_points.add_entry_point(updated_receipts);
     entry_points.add_entry_point(register_owner);
@@ -2509,6 +4546,33 @@ fn install_contract() {
     )
     .unwrap_or_revert();
 
+    // The collection-wide information beyond its name and symbol: a contract level metadata uri,
+    // a description, a banner image and an external link to the collection website.
+    // These values can be changed post installation via set_variables.
+    let contract_uri: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_CONTRACT_URI,
+        NFTCoreError::InvalidCollectionMetadata,
+    )
+    .unwrap_or_default();
+
+    let collection_description: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_COLLECTION_DESCRIPTION,
+        NFTCoreError::InvalidCollectionMetadata,
+    )
+    .unwrap_or_default();
+
+    let collection_image: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_COLLECTION_IMAGE,
+        NFTCoreError::InvalidCollectionMetadata,
+    )
+    .unwrap_or_default();
+
+    let collection_external_link: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_COLLECTION_EXTERNAL_LINK,
+        NFTCoreError::InvalidCollectionMetadata,
+    )
+    .unwrap_or_default();
+
     // This represents the total number of NFTs that will
     // be minted by a specific instance of a contract.
     // This value cannot be changed after installation.
@@ -2649,6 +4713,12 @@ fn install_contract() {
     )
     .unwrap_or_default();
 
+    // The base uri the token uris of the BaseURI metadata kind are derived from, by appending the
+    // token identifier. This value can be changed post installation via set_variables.
+    let base_uri: String =
+        utils::get_optional_named_arg_with_user_errors(ARG_BASE_URI, NFTCoreError::InvalidBaseUri)
+            .unwrap_or_default();
+
     // Represents whether NFTs minted by a given contract will be identified
     // by an ordinal u64 index or a base16 encoded SHA256 hash of an NFTs metadata.
     // This value cannot be changed after installation. Refer to `NFTIdentifierMode` in
@@ -2670,10 +4740,6 @@ fn install_contract() {
     )
     .unwrap_or_revert();
 
-    if identifier_mode == 1 && metadata_mutability == 1 {
-        runtime::revert(NFTCoreError::InvalidMetadataMutability)
-    }
-
     // Represents whether the minted tokens can be burnt.
     // This value cannot be changed post installation. Refer to `BurnMode` in
     // `src/modalities.rs` for further details.
@@ -2707,6 +4773,173 @@ fn install_contract() {
             NFTCoreError::InvalidTransferFilterContract,
         );
 
+    // The fields of the built-in CEP78 and NFT721 schemas which set_token_metadata cannot change.
+    let locked_metadata_fields: Vec<String> = utils::get_optional_named_arg_with_user_errors(
+        ARG_LOCKED_METADATA_FIELDS,
+        NFTCoreError::InvalidLockedMetadataFields,
+    )
+    .unwrap_or_default();
+
+    // Whether CEP78 checksums must be SHA-256 hex digests and token uris use an allowed scheme.
+    // These values cannot be changed after installation.
+    let strict_metadata_validation: bool = utils::get_optional_named_arg_with_user_errors(
+        ARG_STRICT_METADATA_VALIDATION,
+        NFTCoreError::InvalidStrictMetadataValidation,
+    )
+    .unwrap_or_default();
+
+    let allowed_uri_schemes: Vec<String> = utils::get_optional_named_arg_with_user_errors(
+        ARG_ALLOWED_URI_SCHEMES,
+        NFTCoreError::InvalidAllowedUriSchemes,
+    )
+    .unwrap_or_default();
+
+    // The maximum size in bytes of the serialized token metadata accepted by mint and
+    // set_token_metadata, 0 meaning unlimited. This value cannot be changed after installation.
+    let max_metadata_size: u64 = utils::get_optional_named_arg_with_user_errors(
+        ARG_MAX_METADATA_SIZE,
+        NFTCoreError::InvalidMaxMetadataSize,
+    )
+    .unwrap_or_default();
+
+    // The types of the fields of metadata of the Binary kind, in order. Refer to `BinaryFieldType`
+    // in `src/metadata.rs` for further details. This value cannot be changed after installation.
+    let binary_metadata_layout: Vec<u8> = utils::get_optional_named_arg_with_user_errors(
+        ARG_BINARY_METADATA_LAYOUT,
+        NFTCoreError::InvalidBinaryMetadataLayout,
+    )
+    .unwrap_or_default();
+
+    // Represents who, besides the token owner, can update the metadata of a token.
+    // This value cannot be changed after installation. Refer to `MetadataUpdateMode` in
+    // `src/modalities.rs` for further details.
+    let metadata_update_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_METADATA_UPDATE_MODE,
+        NFTCoreError::InvalidMetadataUpdateMode,
+    )
+    .unwrap_or(0u8);
+
+    // Whether validated metadata is stored pretty printed or as compact canonical JSON.
+    // This value cannot be changed after installation. Refer to `MetadataStorageFormat` in
+    // `src/modalities.rs` for further details.
+    let metadata_storage_format: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_METADATA_STORAGE_FORMAT,
+        NFTCoreError::InvalidMetadataStorageFormat,
+    )
+    .unwrap_or(0u8);
+
+    // Whether the base uri of the BaseURI metadata kind is revealed at a later point, tokens
+    // pointing to the placeholder uri until then. This value cannot be changed after
+    // installation. Refer to `RevealMode` in `src/modalities.rs` for further details.
+    let reveal_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_REVEAL_MODE,
+        NFTCoreError::InvalidRevealMode,
+    )
+    .unwrap_or(0u8);
+
+    // The base16 encoded blake2b hash of the base uri to reveal in the Delayed reveal mode.
+    let provenance_hash: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_PROVENANCE_HASH,
+        NFTCoreError::InvalidProvenanceHash,
+    )
+    .unwrap_or_default();
+
+    // The token uri of every token until the base uri is revealed in the Delayed reveal mode.
+    let placeholder_uri: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_PLACEHOLDER_URI,
+        NFTCoreError::InvalidPlaceholderUri,
+    )
+    .unwrap_or_default();
+
+    // Whether ordinal indices are assigned in mint order or drawn pseudo-randomly from the indices
+    // not minted yet. This value cannot be changed after installation. Refer to
+    // `OrdinalAssignmentMode` in `src/modalities.rs` for further details.
+    let ordinal_assignment_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_ORDINAL_ASSIGNMENT_MODE,
+        NFTCoreError::InvalidOrdinalAssignmentMode,
+    )
+    .unwrap_or(0u8);
+
+    // The seed the shuffled indices are drawn from, along with the deploy and block data of each
+    // mint. It is stored in plain text under the contract's named keys, so it is public and only
+    // varies the draws between collections rather than keeping them secret.
+    let shuffle_seed: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_SHUFFLE_SEED,
+        NFTCoreError::InvalidShuffleSeed,
+    )
+    .unwrap_or_default();
+
+    // Whether the token_hash of the Hash identifier mode is any string, falling back to the hash
+    // of the metadata, or a custom identifier required at mint and validated against the rules
+    // below. This value cannot be changed after installation. Refer to `HashIdentifierMode` in
+    // `src/modalities.rs` for further details.
+    let hash_identifier_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_HASH_IDENTIFIER_MODE,
+        NFTCoreError::InvalidHashIdentifierMode,
+    )
+    .unwrap_or(0u8);
+
+    // Hashes derived from the metadata would no longer match it once updated, so only custom
+    // identifiers can be used along with mutable metadata.
+    if identifier_mode == 1 && metadata_mutability == 1 && hash_identifier_mode != 1 {
+        runtime::revert(NFTCoreError::InvalidMetadataMutability)
+    }
+
+    // The rules custom identifiers are validated against: the allowed characters, which default
+    // to ASCII letters, digits, `-` and `_`, the bounds of their length and a prefix they must
+    // start with. These values cannot be changed after installation.
+    let identifier_charset: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_IDENTIFIER_CHARSET,
+        NFTCoreError::InvalidIdentifierRules,
+    )
+    .unwrap_or_default();
+
+    let identifier_min_length: u32 = utils::get_optional_named_arg_with_user_errors(
+        ARG_IDENTIFIER_MIN_LENGTH,
+        NFTCoreError::InvalidIdentifierRules,
+    )
+    .unwrap_or(1u32);
+
+    let identifier_max_length: u32 = utils::get_optional_named_arg_with_user_errors(
+        ARG_IDENTIFIER_MAX_LENGTH,
+        NFTCoreError::InvalidIdentifierRules,
+    )
+    .unwrap_or(MAX_CUSTOM_IDENTIFIER_LENGTH);
+
+    let identifier_prefix: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_IDENTIFIER_PREFIX,
+        NFTCoreError::InvalidIdentifierRules,
+    )
+    .unwrap_or_default();
+
+    // Whether burning a token keeps its state, overwrites its approval, metadata and hash lookup
+    // entries with empty values, or also allows its hash identifier to be minted again. This value
+    // cannot be changed after installation. Refer to `BurnStorageMode` in `src/modalities.rs` for
+    // further details.
+    let burn_storage_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_BURN_STORAGE_MODE,
+        NFTCoreError::InvalidBurnStorageMode,
+    )
+    .unwrap_or(0u8);
+
+    // Whether the installer can burn or transfer any token without the consent of its owner, so
+    // that collectors know in advance whether tokens can be seized. This value cannot be changed
+    // after installation. Refer to `ClawbackMode` in `src/modalities.rs` for further details.
+    let clawback_mode: u8 = utils::get_optional_named_arg_with_user_errors(
+        ARG_CLAWBACK_MODE,
+        NFTCoreError::InvalidClawbackMode,
+    )
+    .unwrap_or(0u8);
+
+    // The account or contract allowed to update the metadata of any token in the Delegated
+    // metadata update mode. It can be changed post installation with set_variables.
+    let metadata_editor: Option<Key> =
+        utils::get_optional_named_arg_with_user_errors::<Option<Key>>(
+            ARG_METADATA_EDITOR,
+            NFTCoreError::InvalidMetadataEditor,
+        )
+        .unwrap_or_default();
+
     if ownership_mode == 0 && minting_mode == 0 && reporting_mode == 1 {
         runtime::revert(NFTCoreError::InvalidReportingMode)
     }
@@ -2760,6 +4993,10 @@ fn install_contract() {
     let args = runtime_args! {
         ARG_COLLECTION_NAME => collection_name,
         ARG_COLLECTION_SYMBOL => collection_symbol,
+        ARG_CONTRACT_URI => contract_uri,
+        ARG_COLLECTION_DESCRIPTION => collection_description,
+        ARG_COLLECTION_IMAGE => collection_image,
+        ARG_COLLECTION_EXTERNAL_LINK => collection_external_link,
         ARG_TOTAL_TOKEN_SUPPLY => total_token_supply,
         ARG_ALLOW_MINTING => allow_minting,
         ARG_OWNERSHIP_MODE => ownership_mode,
@@ -2769,6 +5006,7 @@ fn install_contract() {
         ARG_WHITELIST_MODE => whitelist_lock,
         ARG_ACL_WHITELIST => acl_white_list,
         ARG_JSON_SCHEMA => json_schema,
+        ARG_BASE_URI => base_uri,
         ARG_RECEIPT_NAME => receipt_name,
         ARG_NFT_METADATA_KIND => base_metadata_kind,
         ARG_ADDITIONAL_REQUIRED_METADATA => additional_required_metadata,
@@ -2784,6 +5022,26 @@ fn install_contract() {
         ARG_PACKAGE_OPERATOR_MODE => package_operator_mode,
         ARG_TRANSFER_FILTER_CONTRACT =>
         transfer_filter_contract_contract_key,
+        ARG_LOCKED_METADATA_FIELDS => locked_metadata_fields,
+        ARG_STRICT_METADATA_VALIDATION => strict_metadata_validation,
+        ARG_ALLOWED_URI_SCHEMES => allowed_uri_schemes,
+        ARG_MAX_METADATA_SIZE => max_metadata_size,
+        ARG_METADATA_STORAGE_FORMAT => metadata_storage_format,
+        ARG_BINARY_METADATA_LAYOUT => binary_metadata_layout,
+        ARG_METADATA_UPDATE_MODE => metadata_update_mode,
+        ARG_METADATA_EDITOR => metadata_editor,
+        ARG_REVEAL_MODE => reveal_mode,
+        ARG_PROVENANCE_HASH => provenance_hash,
+        ARG_PLACEHOLDER_URI => placeholder_uri,
+        ARG_ORDINAL_ASSIGNMENT_MODE => ordinal_assignment_mode,
+        ARG_SHUFFLE_SEED => shuffle_seed,
+        ARG_HASH_IDENTIFIER_MODE => hash_identifier_mode,
+        ARG_IDENTIFIER_CHARSET => identifier_charset,
+        ARG_IDENTIFIER_MIN_LENGTH => identifier_min_length,
+        ARG_IDENTIFIER_MAX_LENGTH => identifier_max_length,
+        ARG_IDENTIFIER_PREFIX => identifier_prefix,
+        ARG_BURN_STORAGE_MODE => burn_storage_mode,
+        ARG_CLAWBACK_MODE => clawback_mode,
     };
 
     // Call contract to initialize it
@@ -1,10 +1,13 @@
 use alloc::{
     collections::BTreeMap,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
+use core::convert::TryFrom;
 
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{
@@ -14,16 +17,182 @@ use casper_types::{
 };
 
 use crate::{
-    modalities::NFTMetadataKind, utils, NFTCoreError, ARG_JSON_SCHEMA, METADATA_CEP78,
+    modalities::{MetadataStorageFormat, NFTMetadataKind},
+    utils, NFTCoreError, ARG_JSON_SCHEMA, BINARY_METADATA_LAYOUT, METADATA_BINARY, METADATA_CEP78,
     METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW,
 };
 
+// The JSON type a custom metadata attribute must have.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+#[repr(u8)]
+pub(crate) enum MetadataPropertyType {
+    String = 0,
+    Number = 1,
+    #[serde(alias = "boolean")]
+    Bool = 2,
+    Array = 3,
+    Object = 4,
+}
+
+impl TryFrom<u8> for MetadataPropertyType {
+    type Error = bytesrepr::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MetadataPropertyType::String),
+            1 => Ok(MetadataPropertyType::Number),
+            2 => Ok(MetadataPropertyType::Bool),
+            3 => Ok(MetadataPropertyType::Array),
+            4 => Ok(MetadataPropertyType::Object),
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+impl ToBytes for MetadataPropertyType {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        (*self as u8).to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        (*self as u8).serialized_length()
+    }
+}
+
+impl FromBytes for MetadataPropertyType {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (property_type, remainder) = u8::from_bytes(bytes)?;
+        Ok((MetadataPropertyType::try_from(property_type)?, remainder))
+    }
+}
+
+// The type of a field of binary metadata, which is serialized with bytesrepr.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub(crate) enum BinaryFieldType {
+    Bool = 0,
+    U8 = 1,
+    U32 = 2,
+    U64 = 3,
+    I32 = 4,
+    I64 = 5,
+    String = 6,
+    Bytes = 7,
+}
+
+impl TryFrom<u8> for BinaryFieldType {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BinaryFieldType::Bool),
+            1 => Ok(BinaryFieldType::U8),
+            2 => Ok(BinaryFieldType::U32),
+            3 => Ok(BinaryFieldType::U64),
+            4 => Ok(BinaryFieldType::I32),
+            5 => Ok(BinaryFieldType::I64),
+            6 => Ok(BinaryFieldType::String),
+            7 => Ok(BinaryFieldType::Bytes),
+            _ => Err(NFTCoreError::InvalidBinaryMetadataLayout),
+        }
+    }
+}
+
+impl BinaryFieldType {
+    // Returns the bytes following a field of this type, or an error if the field is malformed.
+    fn skip<'a>(&self, bytes: &'a [u8]) -> Result<&'a [u8], bytesrepr::Error> {
+        let remainder = match self {
+            BinaryFieldType::Bool => bool::from_bytes(bytes)?.1,
+            BinaryFieldType::U8 => u8::from_bytes(bytes)?.1,
+            BinaryFieldType::U32 => u32::from_bytes(bytes)?.1,
+            BinaryFieldType::U64 => u64::from_bytes(bytes)?.1,
+            BinaryFieldType::I32 => i32::from_bytes(bytes)?.1,
+            BinaryFieldType::I64 => i64::from_bytes(bytes)?.1,
+            BinaryFieldType::String => String::from_bytes(bytes)?.1,
+            BinaryFieldType::Bytes => Vec::<u8>::from_bytes(bytes)?.1,
+        };
+        Ok(remainder)
+    }
+}
+
+// Checks the layout of binary metadata declared at installation, a non empty list of field types.
+pub(crate) fn validate_binary_metadata_layout(layout: &[u8]) -> Result<(), NFTCoreError> {
+    if layout.is_empty() {
+        return Err(NFTCoreError::MissingBinaryMetadataLayout);
+    }
+    for field_type in layout {
+        BinaryFieldType::try_from(*field_type)?;
+    }
+    Ok(())
+}
+
+// Checks that binary metadata is the bytesrepr serialization of the fields of the declared
+// layout, in order and without trailing bytes.
+pub(crate) fn validate_binary_metadata(token_metadata: &[u8]) -> Result<(), NFTCoreError> {
+    let layout = utils::get_stored_value_with_user_errors::<Vec<u8>>(
+        BINARY_METADATA_LAYOUT,
+        NFTCoreError::MissingBinaryMetadataLayout,
+        NFTCoreError::InvalidBinaryMetadataLayout,
+    );
+    let mut remainder = token_metadata;
+    for field_type in layout {
+        remainder = BinaryFieldType::try_from(field_type)?
+            .skip(remainder)
+            .map_err(|_| NFTCoreError::InvalidBinaryMetadata)?;
+    }
+    if !remainder.is_empty() {
+        return Err(NFTCoreError::InvalidBinaryMetadata);
+    }
+    Ok(())
+}
+
 // Metadata mutability is different from schema mutability.
+// Apart from `required`, every constraint is optional and only checked when present in the
+// schema, so schemas written before these constraints existed keep their behavior.
 #[derive(Serialize, Deserialize, Clone)]
 pub(crate) struct MetadataSchemaProperty {
     name: String,
     description: String,
     required: bool,
+    #[serde(default = "default_mutable")]
+    mutable: bool,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    property_type: Option<MetadataPropertyType>,
+    #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+    allowed_values: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_length: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_length: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    properties: Option<BTreeMap<String, MetadataSchemaProperty>>,
+}
+
+// Properties are mutable unless the schema locks them, matching the behavior of schemas written
+// before per-field mutability.
+fn default_mutable() -> bool {
+    true
+}
+
+impl MetadataSchemaProperty {
+    // Builds an unconstrained property, as used by the built-in CEP78 and NFT721 schemas.
+    fn new(name: &str, description: &str, required: bool) -> Self {
+        MetadataSchemaProperty {
+            name: name.to_string(),
+            description: description.to_string(),
+            required,
+            mutable: true,
+            property_type: None,
+            allowed_values: None,
+            min_length: None,
+            max_length: None,
+            pattern: None,
+            properties: None,
+        }
+    }
 }
 
 impl ToBytes for MetadataSchemaProperty {
@@ -32,6 +201,13 @@ impl ToBytes for MetadataSchemaProperty {
         result.extend(self.name.to_bytes()?);
         result.extend(self.description.to_bytes()?);
         result.extend(self.required.to_bytes()?);
+        result.extend(self.mutable.to_bytes()?);
+        result.extend(self.property_type.to_bytes()?);
+        result.extend(self.allowed_values.to_bytes()?);
+        result.extend(self.min_length.to_bytes()?);
+        result.extend(self.max_length.to_bytes()?);
+        result.extend(self.pattern.to_bytes()?);
+        result.extend(self.properties.to_bytes()?);
         Ok(result)
     }
 
@@ -39,6 +215,13 @@ impl ToBytes for MetadataSchemaProperty {
         self.name.serialized_length()
             + self.description.serialized_length()
             + self.required.serialized_length()
+            + self.mutable.serialized_length()
+            + self.property_type.serialized_length()
+            + self.allowed_values.serialized_length()
+            + self.min_length.serialized_length()
+            + self.max_length.serialized_length()
+            + self.pattern.serialized_length()
+            + self.properties.serialized_length()
     }
 }
 
@@ -47,10 +230,25 @@ impl FromBytes for MetadataSchemaProperty {
         let (name, remainder) = String::from_bytes(bytes)?;
         let (description, remainder) = String::from_bytes(remainder)?;
         let (required, remainder) = bool::from_bytes(remainder)?;
+        let (mutable, remainder) = bool::from_bytes(remainder)?;
+        let (property_type, remainder) = Option::<MetadataPropertyType>::from_bytes(remainder)?;
+        let (allowed_values, remainder) = Option::<Vec<String>>::from_bytes(remainder)?;
+        let (min_length, remainder) = Option::<u32>::from_bytes(remainder)?;
+        let (max_length, remainder) = Option::<u32>::from_bytes(remainder)?;
+        let (pattern, remainder) = Option::<String>::from_bytes(remainder)?;
+        let (properties, remainder) =
+            Option::<BTreeMap<String, MetadataSchemaProperty>>::from_bytes(remainder)?;
         let metadata_schema_property = MetadataSchemaProperty {
             name,
             description,
             required,
+            mutable,
+            property_type,
+            allowed_values,
+            min_length,
+            max_length,
+            pattern,
+            properties,
         };
         Ok((metadata_schema_property, remainder))
     }
@@ -69,64 +267,54 @@ pub(crate) struct CustomMetadataSchema {
 
 pub(crate) fn get_metadata_schema(kind: &NFTMetadataKind) -> CustomMetadataSchema {
     match kind {
-        NFTMetadataKind::Raw => CustomMetadataSchema {
-            properties: BTreeMap::new(),
-        },
+        NFTMetadataKind::Raw | NFTMetadataKind::BaseURI | NFTMetadataKind::Binary => {
+            CustomMetadataSchema {
+                properties: BTreeMap::new(),
+            }
+        }
         NFTMetadataKind::NFT721 => {
             let mut properties = BTreeMap::new();
             properties.insert(
                 "name".to_string(),
-                MetadataSchemaProperty {
-                    name: "name".to_string(),
-                    description: "The name of the NFT".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new("name", "The name of the NFT", true),
             );
             properties.insert(
                 "symbol".to_string(),
-                MetadataSchemaProperty {
-                    name: "symbol".to_string(),
-                    description: "The symbol of the NFT collection".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new("symbol", "The symbol of the NFT collection", true),
             );
             properties.insert(
                 "token_uri".to_string(),
-                MetadataSchemaProperty {
-                    name: "token_uri".to_string(),
-                    description: "The URI pointing to an off chain resource".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new(
+                    "token_uri",
+                    "The URI pointing to an off chain resource",
+                    true,
+                ),
             );
-            CustomMetadataSchema { properties }
+            with_locked_fields(CustomMetadataSchema { properties })
         }
         NFTMetadataKind::CEP78 => {
             let mut properties = BTreeMap::new();
             properties.insert(
                 "name".to_string(),
-                MetadataSchemaProperty {
-                    name: "name".to_string(),
-                    description: "The name of the NFT".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new("name", "The name of the NFT", true),
             );
             properties.insert(
                 "token_uri".to_string(),
-                MetadataSchemaProperty {
-                    name: "token_uri".to_string(),
-                    description: "The URI pointing to an off chain resource".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new(
+                    "token_uri",
+                    "The URI pointing to an off chain resource",
+                    true,
+                ),
             );
             properties.insert(
                 "checksum".to_string(),
-                MetadataSchemaProperty {
-                    name: "checksum".to_string(),
-                    description: "A SHA256 hash of the content at the token_uri".to_string(),
-                    required: true,
-                },
+                MetadataSchemaProperty::new(
+                    "checksum",
+                    "A SHA256 hash of the content at the token_uri",
+                    true,
+                ),
             );
-            CustomMetadataSchema { properties }
+            with_locked_fields(CustomMetadataSchema { properties })
         }
         NFTMetadataKind::CustomValidated => {
             let custom_schema_json = utils::get_stored_value_with_user_errors::<String>(
@@ -142,6 +330,50 @@ pub(crate) fn get_metadata_schema(kind: &NFTMetadataKind) -> CustomMetadataSchem
     }
 }
 
+impl CustomMetadataSchema {
+    // Checks that the schema constraints themselves are usable, i.e. that every pattern is
+    // supported and that length bounds are ordered.
+    pub(crate) fn validate(&self) -> Result<(), NFTCoreError> {
+        validate_schema_properties(&self.properties)
+    }
+}
+
+fn validate_schema_properties(
+    properties: &BTreeMap<String, MetadataSchemaProperty>,
+) -> Result<(), NFTCoreError> {
+    for property in properties.values() {
+        if let (Some(min_length), Some(max_length)) = (property.min_length, property.max_length) {
+            if min_length > max_length {
+                return Err(NFTCoreError::InvalidJsonSchema);
+            }
+        }
+        if let Some(pattern) = &property.pattern {
+            Pattern::compile(pattern)?;
+        }
+        if let Some(nested_properties) = &property.properties {
+            validate_schema_properties(nested_properties)?;
+        }
+    }
+    Ok(())
+}
+
+// The built-in schemas are mutable unless the installer locked some of their fields.
+fn with_locked_fields(mut schema: CustomMetadataSchema) -> CustomMetadataSchema {
+    let locked_metadata_fields = utils::get_locked_metadata_fields();
+    for property in schema.properties.values_mut() {
+        property.mutable = !locked_metadata_fields.contains(&property.name);
+    }
+    schema
+}
+
+// Whether the name is a property of the built-in CEP78 or NFT721 schemas, which are the ones
+// fields can be locked for at installation.
+pub(crate) fn is_built_in_property(name: &str) -> bool {
+    [NFTMetadataKind::CEP78, NFTMetadataKind::NFT721]
+        .iter()
+        .any(|kind| get_metadata_schema(kind).properties.contains_key(name))
+}
+
 impl ToBytes for CustomMetadataSchema {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
@@ -184,10 +416,11 @@ pub(crate) struct MetadataCEP78 {
     checksum: String,
 }
 
-// Using a structure for the purposes of serialization formatting.
+// Using a structure for the purposes of serialization formatting. Attribute values may be any
+// JSON value, including numbers, arrays and nested objects.
 #[derive(Serialize, Deserialize)]
 pub(crate) struct CustomMetadata {
-    attributes: BTreeMap<String, String>,
+    attributes: Map<String, Value>,
 }
 
 pub(crate) fn validate_metadata(
@@ -215,8 +448,11 @@ pub(crate) fn validate_metadata(
                     runtime::revert(NFTCoreError::InvalidCEP99Metadata)
                 }
             }
-            serde_json::to_string_pretty(&metadata)
-                .map_err(|_| NFTCoreError::FailedToJsonifyCEP99Metadata)
+            if utils::is_strict_metadata_validation() {
+                validate_checksum(&metadata.checksum)?;
+                validate_token_uri_scheme(&metadata.token_uri, &utils::get_allowed_uri_schemes())?;
+            }
+            to_stored_json(&metadata).map_err(|_| NFTCoreError::FailedToJsonifyCEP99Metadata)
         }
         NFTMetadataKind::NFT721 => {
             let metadata = serde_json_wasm::from_str::<MetadataNFT721>(&token_metadata)
@@ -237,26 +473,420 @@ pub(crate) fn validate_metadata(
                     runtime::revert(NFTCoreError::InvalidNFT721Metadata)
                 }
             }
-            serde_json::to_string_pretty(&metadata)
-                .map_err(|_| NFTCoreError::FailedToJsonifyNFT721Metadata)
+            to_stored_json(&metadata).map_err(|_| NFTCoreError::FailedToJsonifyNFT721Metadata)
         }
         NFTMetadataKind::Raw => Ok(token_metadata),
+        // The token uri is derived from the base uri, there is no metadata to store.
+        NFTMetadataKind::BaseURI => Ok(String::new()),
+        // Binary metadata is passed as bytes and validated by validate_binary_metadata.
+        NFTMetadataKind::Binary => Err(NFTCoreError::InvalidBinaryMetadata),
         NFTMetadataKind::CustomValidated => {
-            let custom_metadata =
-                serde_json_wasm::from_str::<BTreeMap<String, String>>(&token_metadata)
-                    .map(|attributes| CustomMetadata { attributes })
-                    .map_err(|_| NFTCoreError::FailedToParseCustomMetadata)?;
-
-            for (property_name, property_type) in token_schema.properties.iter() {
-                if property_type.required && custom_metadata.attributes.get(property_name).is_none()
-                {
-                    runtime::revert(NFTCoreError::InvalidCustomMetadata)
+            let custom_metadata = serde_json::from_str::<Map<String, Value>>(&token_metadata)
+                .map(|attributes| CustomMetadata { attributes })
+                .map_err(|_| NFTCoreError::FailedToParseCustomMetadata)?;
+
+            validate_custom_attributes(&token_schema.properties, &custom_metadata.attributes)?;
+
+            to_stored_json(&custom_metadata.attributes)
+                .map_err(|_| NFTCoreError::FailedToJsonifyCustomMetadata)
+        }
+    }
+}
+
+// Serializes validated metadata in the storage format chosen at installation. Compact metadata
+// goes through a JSON value first, whose objects are sorted by key, so that it is canonical and
+// its hash can be reproduced off-chain.
+fn to_stored_json<T: Serialize>(metadata: &T) -> Result<String, serde_json::Error> {
+    match utils::get_metadata_storage_format() {
+        MetadataStorageFormat::Pretty => serde_json::to_string_pretty(metadata),
+        MetadataStorageFormat::Compact => {
+            serde_json::to_value(metadata).and_then(|value| serde_json::to_string(&value))
+        }
+    }
+}
+
+// The checksum must be a SHA-256 digest encoded as 64 hexadecimal characters.
+fn validate_checksum(checksum: &str) -> Result<(), NFTCoreError> {
+    if checksum.len() != 64 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(NFTCoreError::InvalidChecksum);
+    }
+    Ok(())
+}
+
+// The token uri must start with one of the allowed schemes, compared case insensitively, and
+// point to something after it.
+fn validate_token_uri_scheme(
+    token_uri: &str,
+    allowed_uri_schemes: &[String],
+) -> Result<(), NFTCoreError> {
+    let is_allowed = allowed_uri_schemes.iter().any(|scheme| {
+        token_uri.len() > scheme.len()
+            && token_uri
+                .get(..scheme.len())
+                .map_or(false, |prefix| prefix.eq_ignore_ascii_case(scheme))
+    });
+    if !is_allowed {
+        return Err(NFTCoreError::DisallowedTokenUriScheme);
+    }
+    Ok(())
+}
+
+// Checks custom metadata attributes against the schema properties, descending into nested
+// objects and arrays of objects for properties declaring their own `properties`.
+fn validate_custom_attributes(
+    properties: &BTreeMap<String, MetadataSchemaProperty>,
+    attributes: &Map<String, Value>,
+) -> Result<(), NFTCoreError> {
+    for (property_name, property) in properties.iter() {
+        match attributes.get(property_name) {
+            Some(value) => validate_custom_attribute(property, value)?,
+            None if property.required => return Err(NFTCoreError::InvalidCustomMetadata),
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+fn validate_custom_attribute(
+    property: &MetadataSchemaProperty,
+    value: &Value,
+) -> Result<(), NFTCoreError> {
+    if let Some(property_type) = property.property_type {
+        let has_type = match property_type {
+            MetadataPropertyType::String => value.is_string(),
+            MetadataPropertyType::Number => value.is_number(),
+            MetadataPropertyType::Bool => value.is_boolean(),
+            MetadataPropertyType::Array => value.is_array(),
+            MetadataPropertyType::Object => value.is_object(),
+        };
+        if !has_type {
+            return Err(NFTCoreError::InvalidCustomMetadata);
+        }
+    }
+
+    // Enum values are listed as strings, non string values are compared by their JSON form.
+    if let Some(allowed_values) = &property.allowed_values {
+        let value_string = match value {
+            Value::String(value) => value.clone(),
+            value => value.to_string(),
+        };
+        if !allowed_values.contains(&value_string) {
+            return Err(NFTCoreError::InvalidCustomMetadata);
+        }
+    }
+
+    // Length bounds apply to the characters of a string or the items of an array.
+    let length = match value {
+        Value::String(value) => Some(value.chars().count()),
+        Value::Array(items) => Some(items.len()),
+        _ => None,
+    };
+    if let Some(length) = length {
+        let too_short = property
+            .min_length
+            .map_or(false, |min_length| length < min_length as usize);
+        let too_long = property
+            .max_length
+            .map_or(false, |max_length| length > max_length as usize);
+        if too_short || too_long {
+            return Err(NFTCoreError::InvalidCustomMetadata);
+        }
+    }
+
+    if let (Some(pattern), Value::String(value)) = (&property.pattern, value) {
+        if !Pattern::compile(pattern)?.is_match(value) {
+            return Err(NFTCoreError::InvalidCustomMetadata);
+        }
+    }
+
+    // Nested properties apply to an object value, or to every object item of an array value such
+    // as a list of `{"trait_type": .., "value": ..}` attributes.
+    if let Some(nested_properties) = &property.properties {
+        match value {
+            Value::Object(nested_attributes) => {
+                validate_custom_attributes(nested_properties, nested_attributes)?
+            }
+            Value::Array(items) => {
+                for item in items {
+                    match item {
+                        Value::Object(nested_attributes) => {
+                            validate_custom_attributes(nested_properties, nested_attributes)?
+                        }
+                        _ => return Err(NFTCoreError::InvalidCustomMetadata),
+                    }
                 }
             }
-            serde_json::to_string_pretty(&custom_metadata.attributes)
-                .map_err(|_| NFTCoreError::FailedToJsonifyCustomMetadata)
+            _ => {}
         }
     }
+
+    Ok(())
+}
+
+// The contract has no regular expression engine available, so schema patterns support a subset
+// of the syntax: literals, `.`, character classes such as `[a-z0-9_]` or `[^ ]`, the `\d`, `\w`
+// and `\s` shorthands, the `*`, `+` and `?` quantifiers and the `^` and `$` anchors. As in JSON
+// schema, an unanchored pattern may match anywhere in the value.
+enum PatternAtom {
+    Any,
+    Literal(char),
+    Class {
+        ranges: Vec<(char, char)>,
+        negated: bool,
+    },
+}
+
+impl PatternAtom {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            PatternAtom::Any => true,
+            PatternAtom::Literal(literal) => *literal == c,
+            PatternAtom::Class { ranges, negated } => {
+                ranges.iter().any(|(start, end)| *start <= c && c <= *end) != *negated
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Eq)]
+enum PatternQuantifier {
+    One,
+    ZeroOrOne,
+    ZeroOrMore,
+    OneOrMore,
+}
+
+struct Pattern {
+    tokens: Vec<(PatternAtom, PatternQuantifier)>,
+    anchored_start: bool,
+    anchored_end: bool,
+}
+
+fn shorthand_ranges(c: char) -> Option<Vec<(char, char)>> {
+    match c {
+        'd' => Some(vec![('0', '9')]),
+        'w' => Some(vec![('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_')]),
+        's' => Some(vec![(' ', ' '), ('\t', '\t'), ('\n', '\n'), ('\r', '\r')]),
+        _ => None,
+    }
+}
+
+impl Pattern {
+    fn compile(pattern: &str) -> Result<Self, NFTCoreError> {
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut index = 0;
+        let anchored_start = chars.first() == Some(&'^');
+        if anchored_start {
+            index += 1;
+        }
+        let mut anchored_end = false;
+        let mut tokens = Vec::new();
+
+        while index < chars.len() {
+            let c = chars[index];
+            index += 1;
+            let atom = match c {
+                '$' if index == chars.len() => {
+                    anchored_end = true;
+                    break;
+                }
+                '.' => PatternAtom::Any,
+                '\\' => {
+                    let escaped = *chars.get(index).ok_or(NFTCoreError::InvalidJsonSchema)?;
+                    index += 1;
+                    match shorthand_ranges(escaped) {
+                        Some(ranges) => PatternAtom::Class {
+                            ranges,
+                            negated: false,
+                        },
+                        None => PatternAtom::Literal(escaped),
+                    }
+                }
+                '[' => {
+                    let negated = chars.get(index) == Some(&'^');
+                    if negated {
+                        index += 1;
+                    }
+                    let mut ranges = Vec::new();
+                    loop {
+                        let start = *chars.get(index).ok_or(NFTCoreError::InvalidJsonSchema)?;
+                        index += 1;
+                        if start == ']' && !ranges.is_empty() {
+                            break;
+                        }
+                        let start = if start == '\\' {
+                            let escaped =
+                                *chars.get(index).ok_or(NFTCoreError::InvalidJsonSchema)?;
+                            index += 1;
+                            if let Some(shorthand) = shorthand_ranges(escaped) {
+                                ranges.extend(shorthand);
+                                continue;
+                            }
+                            escaped
+                        } else {
+                            start
+                        };
+                        match (chars.get(index), chars.get(index + 1)) {
+                            (Some('-'), Some(end)) if *end != ']' => {
+                                if *end < start {
+                                    return Err(NFTCoreError::InvalidJsonSchema);
+                                }
+                                ranges.push((start, *end));
+                                index += 2;
+                            }
+                            _ => ranges.push((start, start)),
+                        }
+                    }
+                    PatternAtom::Class { ranges, negated }
+                }
+                '*' | '+' | '?' | '(' | ')' | '|' | '{' | '}' | '^' | '$' => {
+                    return Err(NFTCoreError::InvalidJsonSchema)
+                }
+                c => PatternAtom::Literal(c),
+            };
+            let quantifier = match chars.get(index) {
+                Some('?') => PatternQuantifier::ZeroOrOne,
+                Some('*') => PatternQuantifier::ZeroOrMore,
+                Some('+') => PatternQuantifier::OneOrMore,
+                _ => PatternQuantifier::One,
+            };
+            if quantifier != PatternQuantifier::One {
+                index += 1;
+            }
+            tokens.push((atom, quantifier));
+        }
+
+        Ok(Pattern {
+            tokens,
+            anchored_start,
+            anchored_end,
+        })
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        let chars: Vec<char> = value.chars().collect();
+        // The outcome of matching the tokens from a given token index at a given position does
+        // not depend on how that position was reached, so it is computed once. This bounds the
+        // work of patterns such as `a*a*a*b`, which would otherwise backtrack exponentially.
+        let mut memo = vec![None; (self.tokens.len() + 1) * (chars.len() + 1)];
+        if self.anchored_start {
+            return self.matches_from(0, 0, &chars, &mut memo);
+        }
+        (0..=chars.len()).any(|start| self.matches_from(0, start, &chars, &mut memo))
+    }
+
+    // Backtracking match of the tokens starting at token_index against the text starting at
+    // position, memoised per (token_index, position).
+    fn matches_from(
+        &self,
+        token_index: usize,
+        position: usize,
+        text: &[char],
+        memo: &mut [Option<bool>],
+    ) -> bool {
+        let memo_index = token_index * (text.len() + 1) + position;
+        if let Some(matched) = memo[memo_index] {
+            return matched;
+        }
+        let (atom, quantifier) = match self.tokens.get(token_index) {
+            Some(token) => token,
+            None => return !self.anchored_end || position == text.len(),
+        };
+        let matches_one = position < text.len() && atom.matches(text[position]);
+        let matched = match quantifier {
+            PatternQuantifier::One => {
+                matches_one && self.matches_from(token_index + 1, position + 1, text, memo)
+            }
+            PatternQuantifier::ZeroOrOne => {
+                (matches_one && self.matches_from(token_index + 1, position + 1, text, memo))
+                    || self.matches_from(token_index + 1, position, text, memo)
+            }
+            PatternQuantifier::ZeroOrMore | PatternQuantifier::OneOrMore => {
+                let minimum = if *quantifier == PatternQuantifier::OneOrMore {
+                    1
+                } else {
+                    0
+                };
+                let maximum = text[position..]
+                    .iter()
+                    .take_while(|c| atom.matches(**c))
+                    .count();
+                (minimum..=maximum)
+                    .rev()
+                    .any(|count| self.matches_from(token_index + 1, position + count, text, memo))
+            }
+        };
+        memo[memo_index] = Some(matched);
+        matched
+    }
+}
+
+// Checks that an update of already validated metadata leaves the fields locked by the schema
+// unchanged.
+pub(crate) fn validate_metadata_update(
+    metadata_kind: &NFTMetadataKind,
+    current_token_metadata: &str,
+    updated_token_metadata: &str,
+) -> Result<(), NFTCoreError> {
+    let token_schema = get_metadata_schema(metadata_kind);
+    if token_schema.properties.is_empty() {
+        return Ok(());
+    }
+    let current_attributes = serde_json::from_str::<Map<String, Value>>(current_token_metadata)
+        .map_err(|_| NFTCoreError::FailedToParseCustomMetadata)?;
+    let updated_attributes = serde_json::from_str::<Map<String, Value>>(updated_token_metadata)
+        .map_err(|_| NFTCoreError::FailedToParseCustomMetadata)?;
+    validate_locked_attributes(
+        &token_schema.properties,
+        &current_attributes,
+        &updated_attributes,
+    )
+}
+
+fn validate_locked_attributes(
+    properties: &BTreeMap<String, MetadataSchemaProperty>,
+    current_attributes: &Map<String, Value>,
+    updated_attributes: &Map<String, Value>,
+) -> Result<(), NFTCoreError> {
+    for (property_name, property) in properties.iter() {
+        let current_value = current_attributes.get(property_name);
+        let updated_value = updated_attributes.get(property_name);
+        if !property.mutable {
+            if current_value != updated_value {
+                return Err(NFTCoreError::LockedMetadataField);
+            }
+        } else if let Some(nested_properties) = &property.properties {
+            // Locked fields of a nested object, or of every object item of an array, stay locked
+            // even if the object or array itself may change. Array items are compared by position.
+            let empty_attributes = Map::new();
+            let current_nested = get_nested_attributes(current_value);
+            let updated_nested = get_nested_attributes(updated_value);
+            for index in 0..current_nested.len().max(updated_nested.len()) {
+                validate_locked_attributes(
+                    nested_properties,
+                    current_nested
+                        .get(index)
+                        .copied()
+                        .unwrap_or(&empty_attributes),
+                    updated_nested
+                        .get(index)
+                        .copied()
+                        .unwrap_or(&empty_attributes),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Returns the attributes a nested property applies to: the object value itself, or the object
+// items of an array value.
+fn get_nested_attributes(value: Option<&Value>) -> Vec<&Map<String, Value>> {
+    match value {
+        Some(Value::Object(attributes)) => vec![attributes],
+        Some(Value::Array(items)) => items.iter().filter_map(Value::as_object).collect(),
+        _ => Vec::new(),
+    }
 }
 
 pub(crate) fn get_metadata_dictionary_name(metadata_kind: &NFTMetadataKind) -> String {
@@ -265,6 +895,9 @@ pub(crate) fn get_metadata_dictionary_name(metadata_kind: &NFTMetadataKind) -> S
         NFTMetadataKind::NFT721 => METADATA_NFT721,
         NFTMetadataKind::Raw => METADATA_RAW,
         NFTMetadataKind::CustomValidated => METADATA_CUSTOM_VALIDATED,
+        NFTMetadataKind::Binary => METADATA_BINARY,
+        // Token uris of the BaseURI kind are derived rather than stored in a dictionary.
+        NFTMetadataKind::BaseURI => runtime::revert(NFTCoreError::InvalidNFTMetadataKind),
     };
     name.to_string()
 }
@@ -7,7 +7,7 @@ use alloc::{
 
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
-    CLType, CLTyped,
+    CLType, CLTyped, Key,
 };
 
 use core::convert::TryFrom;
@@ -163,6 +163,8 @@ pub enum NFTMetadataKind {
     NFT721 = 1,
     Raw = 2,
     CustomValidated = 3,
+    BaseURI = 4,
+    Binary = 5,
 }
 
 impl TryFrom<u8> for NFTMetadataKind {
@@ -174,6 +176,8 @@ impl TryFrom<u8> for NFTMetadataKind {
             1 => Ok(NFTMetadataKind::NFT721),
             2 => Ok(NFTMetadataKind::Raw),
             3 => Ok(NFTMetadataKind::CustomValidated),
+            4 => Ok(NFTMetadataKind::BaseURI),
+            5 => Ok(NFTMetadataKind::Binary),
             _ => Err(NFTCoreError::InvalidNFTMetadataKind),
         }
     }
@@ -250,6 +254,25 @@ impl TryFrom<u8> for NFTIdentifierMode {
     }
 }
 
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum HashIdentifierMode {
+    Derived = 0,
+    Custom = 1,
+}
+
+impl TryFrom<u8> for HashIdentifierMode {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(HashIdentifierMode::Derived),
+            1 => Ok(HashIdentifierMode::Custom),
+            _ => Err(NFTCoreError::InvalidHashIdentifierMode),
+        }
+    }
+}
+
 #[repr(u8)]
 pub enum MetadataMutability {
     Immutable = 0,
@@ -268,6 +291,82 @@ impl TryFrom<u8> for MetadataMutability {
     }
 }
 
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MetadataUpdateMode {
+    OwnerOnly = 0,
+    Delegated = 1,
+}
+
+impl TryFrom<u8> for MetadataUpdateMode {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MetadataUpdateMode::OwnerOnly),
+            1 => Ok(MetadataUpdateMode::Delegated),
+            _ => Err(NFTCoreError::InvalidMetadataUpdateMode),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MetadataStorageFormat {
+    Pretty = 0,
+    Compact = 1,
+}
+
+impl TryFrom<u8> for MetadataStorageFormat {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MetadataStorageFormat::Pretty),
+            1 => Ok(MetadataStorageFormat::Compact),
+            _ => Err(NFTCoreError::InvalidMetadataStorageFormat),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum RevealMode {
+    NoReveal = 0,
+    Delayed = 1,
+}
+
+impl TryFrom<u8> for RevealMode {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(RevealMode::NoReveal),
+            1 => Ok(RevealMode::Delayed),
+            _ => Err(NFTCoreError::InvalidRevealMode),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum OrdinalAssignmentMode {
+    Sequential = 0,
+    Shuffled = 1,
+}
+
+impl TryFrom<u8> for OrdinalAssignmentMode {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(OrdinalAssignmentMode::Sequential),
+            1 => Ok(OrdinalAssignmentMode::Shuffled),
+            _ => Err(NFTCoreError::InvalidOrdinalAssignmentMode),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Clone)]
 pub enum TokenIdentifier {
     Index(u64),
@@ -363,6 +462,71 @@ impl CLTyped for TokenIdentifier {
     }
 }
 
+// The state of a token returned by the token_info entrypoint. The metadata is keyed by the u8
+// value of its kind, binary metadata being base16 encoded.
+#[derive(PartialEq, Eq, Debug)]
+pub struct TokenInfo {
+    pub token_index: u64,
+    pub token_hash: Option<String>,
+    pub owner: Key,
+    pub issuer: Key,
+    pub approved: Option<Key>,
+    pub burnt: bool,
+    pub metadata: BTreeMap<u8, String>,
+}
+
+impl ToBytes for TokenInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.token_index.to_bytes()?);
+        result.extend(self.token_hash.to_bytes()?);
+        result.extend(self.owner.to_bytes()?);
+        result.extend(self.issuer.to_bytes()?);
+        result.extend(self.approved.to_bytes()?);
+        result.extend(self.burnt.to_bytes()?);
+        result.extend(self.metadata.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.token_index.serialized_length()
+            + self.token_hash.serialized_length()
+            + self.owner.serialized_length()
+            + self.issuer.serialized_length()
+            + self.approved.serialized_length()
+            + self.burnt.serialized_length()
+            + self.metadata.serialized_length()
+    }
+}
+
+impl FromBytes for TokenInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (token_index, remainder) = u64::from_bytes(bytes)?;
+        let (token_hash, remainder) = Option::<String>::from_bytes(remainder)?;
+        let (owner, remainder) = Key::from_bytes(remainder)?;
+        let (issuer, remainder) = Key::from_bytes(remainder)?;
+        let (approved, remainder) = Option::<Key>::from_bytes(remainder)?;
+        let (burnt, remainder) = bool::from_bytes(remainder)?;
+        let (metadata, remainder) = BTreeMap::<u8, String>::from_bytes(remainder)?;
+        let token_info = TokenInfo {
+            token_index,
+            token_hash,
+            owner,
+            issuer,
+            approved,
+            burnt,
+            metadata,
+        };
+        Ok((token_info, remainder))
+    }
+}
+
+impl CLTyped for TokenInfo {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
 #[repr(u8)]
 pub enum BurnMode {
     Burnable = 0,
@@ -381,6 +545,46 @@ impl TryFrom<u8> for BurnMode {
     }
 }
 
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum BurnStorageMode {
+    Retain = 0,
+    Reclaim = 1,
+    ReclaimAndReuse = 2,
+}
+
+impl TryFrom<u8> for BurnStorageMode {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BurnStorageMode::Retain),
+            1 => Ok(BurnStorageMode::Reclaim),
+            2 => Ok(BurnStorageMode::ReclaimAndReuse),
+            _ => Err(NFTCoreError::InvalidBurnStorageMode),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum ClawbackMode {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+impl TryFrom<u8> for ClawbackMode {
+    type Error = NFTCoreError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ClawbackMode::Disabled),
+            1 => Ok(ClawbackMode::Enabled),
+            _ => Err(NFTCoreError::InvalidClawbackMode),
+        }
+    }
+}
+
 #[repr(u8)]
 #[derive(Clone, PartialEq, Eq)]
 pub enum OwnerReverseLookupMode {
@@ -1,12 +1,16 @@
 use alloc::{
     borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
     format,
     string::{String, ToString},
     vec,
     vec::Vec,
 };
 use casper_event_standard::Schemas;
-use core::{convert::TryInto, mem::MaybeUninit};
+use core::{
+    convert::{TryFrom, TryInto},
+    mem::MaybeUninit,
+};
 
 use casper_contract::{
     contract_api::{self, runtime, storage},
@@ -16,27 +20,43 @@ use casper_contract::{
 use casper_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
     system::CallStackElement,
     ApiError, CLTyped, ContractHash, ContractPackageHash, Key, URef,
 };
 
 use crate::{
     constants::{
-        ACL_WHITELIST, ARG_TOKEN_HASH, ARG_TOKEN_ID, BURNT_TOKENS, BURN_MODE, CONTRACT_WHITELIST,
-        HASH_BY_INDEX, HOLDER_MODE, INDEX_BY_HASH, MIGRATION_FLAG, MINTING_MODE,
-        NUMBER_OF_MINTED_TOKENS, OWNED_TOKENS, OWNERSHIP_MODE, PAGE_LIMIT, PAGE_TABLE,
-        PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_OWNERS,
+        ACL_WHITELIST, ALLOWED_URI_SCHEMES, ARG_COLLECTION_DESCRIPTION,
+        ARG_COLLECTION_EXTERNAL_LINK, ARG_COLLECTION_IMAGE, ARG_CONTRACT_URI, ARG_MEMO,
+        ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_META_DATA_BYTES, BASE_URI,
+        BURNT_TOKENS, BURN_MODE, BURN_STORAGE_MODE, CLAWBACK_MODE, COLLECTION_METADATA,
+        CONTRACT_WHITELIST, DEFAULT_ALLOWED_URI_SCHEMES, FROZEN_METADATA, HASH_BY_INDEX,
+        HASH_IDENTIFIER_MODE, HOLDER_MODE, IDENTIFIER_CHARSET, IDENTIFIER_MAX_LENGTH,
+        IDENTIFIER_MIN_LENGTH, IDENTIFIER_PREFIX, INDEX_BY_HASH, ISSUED_TOKENS,
+        ISSUED_TOKEN_COUNTS, ISSUED_TOKEN_RECORDS, JSON_SCHEMA_VERSION, LOCKED_METADATA_FIELDS,
+        MAX_CUSTOM_IDENTIFIER_LENGTH, MAX_ISSUED_TOKENS_PAGE_SIZE, MAX_MEMO_LENGTH,
+        MAX_METADATA_SIZE, METADATA_BINARY, METADATA_EDITOR, METADATA_HISTORY,
+        METADATA_REVISION_COUNTS, METADATA_STORAGE_FORMAT, METADATA_UPDATE_MODE, MIGRATION_FLAG,
+        MINTING_MODE, NFT_METADATA_KINDS, NUMBER_OF_BURNT_TOKENS, NUMBER_OF_HOLDERS,
+        NUMBER_OF_MINTED_TOKENS, ORDINAL_ASSIGNMENT_MODE, OWNED_TOKENS, OWNERSHIP_MODE, PAGE_LIMIT,
+        PAGE_TABLE, PLACEHOLDER_URI, PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, REMINTED_TOKENS,
+        REPORTING_MODE, REVEALED, REVEAL_MODE, RLO_MFLAG, SHUFFLED_INDICES, SHUFFLE_SEED,
+        STRICT_METADATA_VALIDATION, TOKEN_METADATA_KINDS, TOKEN_OWNERS, TOTAL_TOKEN_SUPPLY,
         TRANSFER_FILTER_CONTRACT, UNMATCHED_HASH_COUNT,
     },
     error::NFTCoreError,
     events::events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
-        Transfer, VariablesSet,
+        Approval, ApprovalForAll, ApprovalRevoked, Burn, CollectionMetadataUpdated, ForcedBurn,
+        ForcedTransfer, MetadataFrozen, MetadataUpdated, Migration, Mint, Revealed, Transfer,
+        VariablesSet,
     },
+    metadata,
     modalities::{
-        BurnMode, MetadataRequirement, MintingMode, NFTHolderMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, Requirement, TokenIdentifier,
+        BurnMode, BurnStorageMode, ClawbackMode, HashIdentifierMode, MetadataRequirement,
+        MetadataStorageFormat, MetadataUpdateMode, MintingMode, NFTHolderMode, NFTIdentifierMode,
+        NFTMetadataKind, OrdinalAssignmentMode, OwnerReverseLookupMode, OwnershipMode, Requirement,
+        RevealMode, TokenIdentifier,
     },
     utils,
 };
@@ -326,6 +346,47 @@ pub fn get_verified_caller() -> Result<Caller, NFTCoreError> {
     }
 }
 
+// Returns the package hash of the currently executing contract.
+pub fn get_current_contract_package_hash() -> ContractPackageHash {
+    match *runtime::get_call_stack()
+        .last()
+        .to_owned()
+        .unwrap_or_revert()
+    {
+        CallStackElement::StoredSession {
+            contract_package_hash,
+            ..
+        }
+        | CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => contract_package_hash,
+        CallStackElement::Session { .. } => runtime::revert(NFTCoreError::InvalidContract),
+    }
+}
+
+// The digest an owner signs to authorize a relayed transfer. The contract package hash binds the
+// authorization to this collection across contract upgrades.
+pub fn transfer_authorization_digest(
+    token_identifier: &TokenIdentifier,
+    target_owner_key: &Key,
+    nonce: u64,
+    deadline: u64,
+) -> [u8; 32] {
+    let message = (
+        get_current_contract_package_hash(),
+        token_identifier.clone(),
+        *target_owner_key,
+        nonce,
+        deadline,
+    )
+        .to_bytes()
+        .unwrap_or_revert();
+    runtime::blake2b(message)
+}
+
+// Tokens of the Hash identifier mode can be passed in either form, the token_id index being
+// resolved through the hash lookup recorded at mint. Ordinal tokens have no unique hash.
 pub fn get_token_identifier_from_runtime_args(
     identifier_mode: &NFTIdentifierMode,
 ) -> TokenIdentifier {
@@ -337,13 +398,101 @@ pub fn get_token_identifier_from_runtime_args(
         )
         .map(TokenIdentifier::new_index)
         .unwrap_or_revert(),
-        NFTIdentifierMode::Hash => get_named_arg_with_user_errors::<String>(
-            ARG_TOKEN_HASH,
-            NFTCoreError::MissingTokenID,
-            NFTCoreError::InvalidTokenIdentifier,
+        NFTIdentifierMode::Hash => {
+            match get_optional_named_arg_with_user_errors::<String>(
+                ARG_TOKEN_HASH,
+                NFTCoreError::InvalidTokenIdentifier,
+            ) {
+                Some(token_hash) => TokenIdentifier::new_hash(token_hash),
+                None => get_named_arg_with_user_errors::<u64>(
+                    ARG_TOKEN_ID,
+                    NFTCoreError::MissingTokenID,
+                    NFTCoreError::InvalidTokenIdentifier,
+                )
+                .map(|token_index| {
+                    TokenIdentifier::new_hash(
+                        get_token_hash(token_index)
+                            .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier),
+                    )
+                })
+                .unwrap_or_revert(),
+            }
+        }
+    }
+}
+
+// Contracts installed before custom identifiers accept any token_hash and otherwise derive the
+// identifier from the metadata.
+pub fn get_hash_identifier_mode() -> HashIdentifierMode {
+    if !named_uref_exists(HASH_IDENTIFIER_MODE) {
+        HashIdentifierMode::Derived
+    } else {
+        get_stored_value_with_user_errors::<u8>(
+            HASH_IDENTIFIER_MODE,
+            NFTCoreError::MissingHashIdentifierMode,
+            NFTCoreError::InvalidHashIdentifierMode,
         )
-        .map(TokenIdentifier::new_hash)
-        .unwrap_or_revert(),
+        .try_into()
+        .unwrap_or_revert()
+    }
+}
+
+// The rules must leave room for at least one character after the prefix, and only use printable
+// ASCII characters which are safe to use in dictionary item keys.
+pub fn validate_identifier_rules(
+    identifier_charset: &str,
+    identifier_min_length: u32,
+    identifier_max_length: u32,
+    identifier_prefix: &str,
+) -> Result<(), NFTCoreError> {
+    let is_key_safe = |value: &str| value.chars().all(|c| c.is_ascii_graphic());
+    if identifier_charset.is_empty()
+        || !is_key_safe(identifier_charset)
+        || !is_key_safe(identifier_prefix)
+        || identifier_min_length == 0
+        || identifier_min_length > identifier_max_length
+        || identifier_max_length > MAX_CUSTOM_IDENTIFIER_LENGTH
+        || identifier_prefix.len() as u32 >= identifier_max_length
+    {
+        return Err(NFTCoreError::InvalidIdentifierRules);
+    }
+    Ok(())
+}
+
+// Custom identifiers start with the prefix, followed by characters of the charset, and their
+// length, prefix included, is within the installed bounds.
+pub fn validate_custom_identifier(token_hash: &str) -> Result<(), NFTCoreError> {
+    let identifier_charset = get_stored_value_with_user_errors::<String>(
+        IDENTIFIER_CHARSET,
+        NFTCoreError::MissingIdentifierRules,
+        NFTCoreError::InvalidIdentifierRules,
+    );
+    let identifier_min_length = get_stored_value_with_user_errors::<u32>(
+        IDENTIFIER_MIN_LENGTH,
+        NFTCoreError::MissingIdentifierRules,
+        NFTCoreError::InvalidIdentifierRules,
+    );
+    let identifier_max_length = get_stored_value_with_user_errors::<u32>(
+        IDENTIFIER_MAX_LENGTH,
+        NFTCoreError::MissingIdentifierRules,
+        NFTCoreError::InvalidIdentifierRules,
+    );
+    let identifier_prefix = get_stored_value_with_user_errors::<String>(
+        IDENTIFIER_PREFIX,
+        NFTCoreError::MissingIdentifierRules,
+        NFTCoreError::InvalidIdentifierRules,
+    );
+    let length = token_hash.len() as u64;
+    if length < identifier_min_length as u64 || length > identifier_max_length as u64 {
+        return Err(NFTCoreError::InvalidCustomIdentifier);
+    }
+    match token_hash.strip_prefix(identifier_prefix.as_str()) {
+        Some(serial)
+            if !serial.is_empty() && serial.chars().all(|c| identifier_charset.contains(c)) =>
+        {
+            Ok(())
+        }
+        _ => Err(NFTCoreError::InvalidCustomIdentifier),
     }
 }
 
@@ -386,9 +535,219 @@ pub fn get_burn_mode() -> BurnMode {
     burn_mode
 }
 
+// Contracts installed before burn storage reclamation retain the state of burnt tokens.
+pub fn get_burn_storage_mode() -> BurnStorageMode {
+    if !named_uref_exists(BURN_STORAGE_MODE) {
+        BurnStorageMode::Retain
+    } else {
+        get_stored_value_with_user_errors::<u8>(
+            BURN_STORAGE_MODE,
+            NFTCoreError::MissingBurnStorageMode,
+            NFTCoreError::InvalidBurnStorageMode,
+        )
+        .try_into()
+        .unwrap_or_revert()
+    }
+}
+
+pub fn get_clawback_mode() -> ClawbackMode {
+    if !named_uref_exists(CLAWBACK_MODE) {
+        // Contracts installed before the clawback mode was introduced never opted in.
+        ClawbackMode::Disabled
+    } else {
+        get_stored_value_with_user_errors::<u8>(
+            CLAWBACK_MODE,
+            NFTCoreError::MissingClawbackMode,
+            NFTCoreError::InvalidClawbackMode,
+        )
+        .try_into()
+        .unwrap_or_revert()
+    }
+}
+
+// Returns the optional memo attached to a mint, transfer or burn. The memo is only recorded in the
+// emitted event, so that integrators can correlate the call with their off-chain records.
+pub fn get_optional_memo() -> Option<String> {
+    let memo: Option<String> =
+        get_optional_named_arg_with_user_errors(ARG_MEMO, NFTCoreError::InvalidMemo);
+    if let Some(memo) = &memo {
+        if memo.len() > MAX_MEMO_LENGTH {
+            runtime::revert(NFTCoreError::InvalidMemo)
+        }
+    }
+    memo
+}
+
+// Appends a token to the tokens issued by its minter. The token is kept in the ISSUED_TOKENS
+// dictionary under its position among the tokens of the issuer, whose count is kept in
+// ISSUED_TOKEN_COUNTS.
+pub fn record_issued_token(issuer: Key, token_identifier: &TokenIdentifier) {
+    // A burnt hash minted again in the ReclaimAndReuse burn storage mode is only listed once per
+    // issuer, which ISSUED_TOKEN_RECORDS keeps track of.
+    if get_burn_storage_mode() == BurnStorageMode::ReclaimAndReuse {
+        let record_item_key =
+            encode_key_and_value(&issuer, &token_identifier.get_dictionary_item_key());
+        if get_dictionary_value_from_key::<bool>(ISSUED_TOKEN_RECORDS, &record_item_key)
+            .unwrap_or_default()
+        {
+            return;
+        }
+        upsert_dictionary_value_from_key(ISSUED_TOKEN_RECORDS, &record_item_key, true);
+    }
+    let issuer_item_key = encode_dictionary_item_key(issuer);
+    let issued_token_count =
+        get_dictionary_value_from_key::<u64>(ISSUED_TOKEN_COUNTS, &issuer_item_key)
+            .unwrap_or_default();
+    upsert_dictionary_value_from_key(
+        ISSUED_TOKENS,
+        &encode_key_and_value(&issuer, &issued_token_count),
+        token_identifier.get_dictionary_item_key(),
+    );
+    upsert_dictionary_value_from_key(
+        ISSUED_TOKEN_COUNTS,
+        &issuer_item_key,
+        issued_token_count + 1u64,
+    );
+}
+
+// Returns the identifiers of up to page_size tokens issued by the issuer, starting at the
+// page_start position in the order they were minted.
+pub fn get_tokens_issued_by(issuer: Key, page_start: u64, page_size: u64) -> Vec<String> {
+    if page_size == 0 || page_size > MAX_ISSUED_TOKENS_PAGE_SIZE {
+        runtime::revert(NFTCoreError::InvalidIssuedTokensPage)
+    }
+    let issued_token_count = get_dictionary_value_from_key::<u64>(
+        ISSUED_TOKEN_COUNTS,
+        &encode_dictionary_item_key(issuer),
+    )
+    .unwrap_or_default();
+    let page_end = issued_token_count.min(page_start.saturating_add(page_size));
+    (page_start..page_end)
+        .map(|position| {
+            get_dictionary_value_from_key::<String>(
+                ISSUED_TOKENS,
+                &encode_key_and_value(&issuer, &position),
+            )
+            .unwrap_or_revert_with(NFTCoreError::InvalidIssuedTokensPage)
+        })
+        .collect()
+}
+
+// Supply statistics are not kept by contracts upgraded while some of their tokens could not be
+// enumerated, see count_supply_statistics.
+pub fn increment_number_of_burnt_tokens() {
+    if !named_uref_exists(NUMBER_OF_BURNT_TOKENS) {
+        return;
+    }
+    let number_of_burnt_tokens_uref = get_uref(
+        NUMBER_OF_BURNT_TOKENS,
+        NFTCoreError::MissingNumberOfBurntTokens,
+        NFTCoreError::InvalidNumberOfBurntTokens,
+    );
+    let number_of_burnt_tokens: u64 = read_with_user_errors(
+        number_of_burnt_tokens_uref,
+        NFTCoreError::MissingNumberOfBurntTokens,
+        NFTCoreError::InvalidNumberOfBurntTokens,
+    );
+    storage::write(number_of_burnt_tokens_uref, number_of_burnt_tokens + 1u64);
+}
+
+// An owner becomes a holder when its balance leaves zero and stops being one when it returns to it.
+pub fn update_number_of_holders(previous_balance: u64, updated_balance: u64) {
+    if !named_uref_exists(NUMBER_OF_HOLDERS) || (previous_balance == 0) == (updated_balance == 0) {
+        return;
+    }
+    let number_of_holders_uref = get_uref(
+        NUMBER_OF_HOLDERS,
+        NFTCoreError::MissingNumberOfHolders,
+        NFTCoreError::InvalidNumberOfHolders,
+    );
+    let number_of_holders: u64 = read_with_user_errors(
+        number_of_holders_uref,
+        NFTCoreError::MissingNumberOfHolders,
+        NFTCoreError::InvalidNumberOfHolders,
+    );
+    let updated_number_of_holders = if previous_balance == 0 {
+        number_of_holders + 1u64
+    } else {
+        number_of_holders - 1u64
+    };
+    storage::write(number_of_holders_uref, updated_number_of_holders);
+}
+
+// Counts the burnt tokens and the distinct holders of the tokens minted before an upgrade. Returns
+// None if some of these tokens cannot be enumerated, as the hashes of the tokens minted in the Hash
+// identifier mode by version 1.0.0 are only indexed once their owner updates its receipts.
+pub fn count_supply_statistics(identifier_mode: &NFTIdentifierMode) -> Option<(u64, u64)> {
+    let number_of_minted_tokens = get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingNumberOfMintedTokens,
+        NFTCoreError::InvalidNumberOfMintedTokens,
+    );
+    let mut number_of_burnt_tokens = 0u64;
+    let mut holders: BTreeSet<Key> = BTreeSet::new();
+    for token_index in 0..number_of_minted_tokens {
+        let token_identifier = match identifier_mode {
+            NFTIdentifierMode::Ordinal => TokenIdentifier::new_index(token_index),
+            NFTIdentifierMode::Hash => TokenIdentifier::new_hash(get_token_hash(token_index)?),
+        };
+        if is_token_burned(&token_identifier) {
+            number_of_burnt_tokens += 1u64;
+        } else {
+            holders.insert(get_token_owner(&token_identifier)?);
+        }
+    }
+    Some((number_of_burnt_tokens, holders.len() as u64))
+}
+
+// Reads the number of burnt tokens or of holders, which are unavailable on contracts upgraded
+// while some of their tokens could not be enumerated.
+pub fn get_supply_statistic(name: &str, invalid_error: NFTCoreError) -> u64 {
+    get_stored_value_with_user_errors::<u64>(
+        name,
+        NFTCoreError::UnavailableSupplyStatistics,
+        invalid_error,
+    )
+}
+
 pub fn is_token_burned(token_identifier: &TokenIdentifier) -> bool {
-    get_dictionary_value_from_key::<()>(BURNT_TOKENS, &token_identifier.get_dictionary_item_key())
-        .is_some()
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+    if get_dictionary_value_from_key::<()>(BURNT_TOKENS, &token_identifier_dictionary_key).is_none()
+    {
+        return false;
+    }
+    // Burnt tokens cannot be removed from BURNT_TOKENS, so a burnt hash minted again in the
+    // ReclaimAndReuse burn storage mode is flagged in REMINTED_TOKENS until it is burnt again.
+    get_burn_storage_mode() != BurnStorageMode::ReclaimAndReuse
+        || !get_dictionary_value_from_key::<bool>(REMINTED_TOKENS, &token_identifier_dictionary_key)
+            .unwrap_or_default()
+}
+
+// The frozen flag of a token is cleared when its storage is reclaimed on burn.
+pub fn is_metadata_frozen(token_identifier: &TokenIdentifier) -> bool {
+    get_dictionary_value_from_key::<bool>(
+        FROZEN_METADATA,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .unwrap_or_default()
+}
+
+// Dictionary entries cannot be removed, so the owner entry of a token whose storage was reclaimed
+// on burn is overwritten with a tombstone, which no account or contract can hold.
+const RECLAIMED_TOKEN_OWNER: Key = Key::Hash([0u8; 32]);
+
+pub fn clear_token_owner(token_identifier: &TokenIdentifier) {
+    upsert_dictionary_value_from_key(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+        RECLAIMED_TOKEN_OWNER,
+    );
+}
+
+// Returns the owner of a token, or None if it has not been minted or its storage was reclaimed.
+pub fn get_token_owner(token_identifier: &TokenIdentifier) -> Option<Key> {
+    get_dictionary_value_from_key::<Key>(TOKEN_OWNERS, &token_identifier.get_dictionary_item_key())
+        .filter(|token_owner| *token_owner != RECLAIMED_TOKEN_OWNER)
 }
 
 pub fn get_transfer_filter_contract() -> Option<ContractHash> {
@@ -403,6 +762,118 @@ pub fn get_transfer_filter_contract() -> Option<ContractHash> {
     }
 }
 
+// Contracts installed before per-field mutability have no locked metadata fields.
+pub fn get_locked_metadata_fields() -> Vec<String> {
+    if !named_uref_exists(LOCKED_METADATA_FIELDS) {
+        vec![]
+    } else {
+        get_stored_value_with_user_errors::<Vec<String>>(
+            LOCKED_METADATA_FIELDS,
+            NFTCoreError::MissingLockedMetadataFields,
+            NFTCoreError::InvalidLockedMetadataFields,
+        )
+    }
+}
+
+// Contracts installed before strict metadata validation only check CEP78 fields are non-empty.
+pub fn is_strict_metadata_validation() -> bool {
+    if !named_uref_exists(STRICT_METADATA_VALIDATION) {
+        false
+    } else {
+        get_stored_value_with_user_errors::<bool>(
+            STRICT_METADATA_VALIDATION,
+            NFTCoreError::MissingStrictMetadataValidation,
+            NFTCoreError::InvalidStrictMetadataValidation,
+        )
+    }
+}
+
+// Contracts installed before the metadata size limit have none, which a limit of 0 stands for.
+pub fn get_max_metadata_size() -> u64 {
+    if !named_uref_exists(MAX_METADATA_SIZE) {
+        0
+    } else {
+        get_stored_value_with_user_errors::<u64>(
+            MAX_METADATA_SIZE,
+            NFTCoreError::MissingMaxMetadataSize,
+            NFTCoreError::InvalidMaxMetadataSize,
+        )
+    }
+}
+
+// Reverts if a serialized token metadata argument exceeds the metadata size limit. The size is
+// read before the argument itself so oversized metadata is rejected without deserializing it.
+pub fn check_token_metadata_size() {
+    let max_metadata_size = get_max_metadata_size();
+    if max_metadata_size == 0 {
+        return;
+    }
+    for arg_name in [ARG_TOKEN_META_DATA, ARG_TOKEN_META_DATA_BYTES] {
+        if let Some(metadata_size) = get_named_arg_size(arg_name) {
+            if metadata_size as u64 > max_metadata_size {
+                runtime::revert(NFTCoreError::MetadataTooLarge)
+            }
+        }
+    }
+}
+
+pub fn get_allowed_uri_schemes() -> Vec<String> {
+    if !named_uref_exists(ALLOWED_URI_SCHEMES) {
+        DEFAULT_ALLOWED_URI_SCHEMES
+            .iter()
+            .map(|scheme| scheme.to_string())
+            .collect()
+    } else {
+        get_stored_value_with_user_errors::<Vec<String>>(
+            ALLOWED_URI_SCHEMES,
+            NFTCoreError::MissingAllowedUriSchemes,
+            NFTCoreError::InvalidAllowedUriSchemes,
+        )
+    }
+}
+
+// Contracts installed before delegated metadata updates only let the token owner update metadata.
+pub fn get_metadata_update_mode() -> MetadataUpdateMode {
+    if !named_uref_exists(METADATA_UPDATE_MODE) {
+        MetadataUpdateMode::OwnerOnly
+    } else {
+        get_stored_value_with_user_errors::<u8>(
+            METADATA_UPDATE_MODE,
+            NFTCoreError::MissingMetadataUpdateMode,
+            NFTCoreError::InvalidMetadataUpdateMode,
+        )
+        .try_into()
+        .unwrap_or_revert()
+    }
+}
+
+// Contracts installed before the compact storage format store pretty printed metadata.
+pub fn get_metadata_storage_format() -> MetadataStorageFormat {
+    if !named_uref_exists(METADATA_STORAGE_FORMAT) {
+        MetadataStorageFormat::Pretty
+    } else {
+        get_stored_value_with_user_errors::<u8>(
+            METADATA_STORAGE_FORMAT,
+            NFTCoreError::MissingMetadataStorageFormat,
+            NFTCoreError::InvalidMetadataStorageFormat,
+        )
+        .try_into()
+        .unwrap_or_revert()
+    }
+}
+
+pub fn get_metadata_editor() -> Option<Key> {
+    if !named_uref_exists(METADATA_EDITOR) {
+        None
+    } else {
+        get_stored_value_with_user_errors::<Option<Key>>(
+            METADATA_EDITOR,
+            NFTCoreError::MissingMetadataEditor,
+            NFTCoreError::InvalidMetadataEditor,
+        )
+    }
+}
+
 pub fn max_number_of_pages(total_token_supply: u64) -> u64 {
     if total_token_supply < PAGE_SIZE {
         let dictionary_name = format!("{PREFIX_PAGE_DICTIONARY}_{}", 0);
@@ -452,7 +923,17 @@ pub fn insert_hash_id_lookups(
     .unwrap_or_revert()
     .is_some()
     {
-        runtime::revert(NFTCoreError::DuplicateIdentifier)
+        // A burnt hash can be minted again in the ReclaimAndReuse burn storage mode.
+        if get_burn_storage_mode() != BurnStorageMode::ReclaimAndReuse
+            || !is_token_burned(&token_identifier)
+        {
+            runtime::revert(NFTCoreError::DuplicateIdentifier)
+        }
+        upsert_dictionary_value_from_key(
+            REMINTED_TOKENS,
+            &token_identifier.get_dictionary_item_key(),
+            true,
+        );
     }
     if storage::dictionary_get::<String>(
         hash_by_index_uref,
@@ -475,6 +956,13 @@ pub fn insert_hash_id_lookups(
     );
 }
 
+// The hash recorded for the index of a token at mint, emptied when the token is burnt in a
+// reclaiming burn storage mode.
+pub fn get_token_hash(token_index: u64) -> Option<String> {
+    get_dictionary_value_from_key::<String>(HASH_BY_INDEX, &token_index.to_string())
+        .filter(|token_hash| !token_hash.is_empty())
+}
+
 pub fn get_token_index(token_identifier: &TokenIdentifier) -> u64 {
     match token_identifier {
         TokenIdentifier::Index(token_index) => *token_index,
@@ -494,6 +982,73 @@ pub fn get_token_index(token_identifier: &TokenIdentifier) -> u64 {
     }
 }
 
+// Contracts installed before shuffled ordinals assign indices in mint order.
+pub fn get_ordinal_assignment_mode() -> OrdinalAssignmentMode {
+    if !named_uref_exists(ORDINAL_ASSIGNMENT_MODE) {
+        OrdinalAssignmentMode::Sequential
+    } else {
+        get_stored_value_with_user_errors::<u8>(
+            ORDINAL_ASSIGNMENT_MODE,
+            NFTCoreError::MissingOrdinalAssignmentMode,
+            NFTCoreError::InvalidOrdinalAssignmentMode,
+        )
+        .try_into()
+        .unwrap_or_revert()
+    }
+}
+
+// The exclusive upper bound of the indices minted so far. Shuffled indices can be anywhere below
+// the total token supply.
+pub fn get_token_index_bound() -> u64 {
+    match get_ordinal_assignment_mode() {
+        OrdinalAssignmentMode::Sequential => get_stored_value_with_user_errors::<u64>(
+            NUMBER_OF_MINTED_TOKENS,
+            NFTCoreError::MissingNumberOfMintedTokens,
+            NFTCoreError::InvalidNumberOfMintedTokens,
+        ),
+        OrdinalAssignmentMode::Shuffled => get_stored_value_with_user_errors::<u64>(
+            TOTAL_TOKEN_SUPPLY,
+            NFTCoreError::MissingTotalTokenSupply,
+            NFTCoreError::InvalidTotalTokenSupply,
+        ),
+    }
+}
+
+// Picks the index of the next token pseudo-randomly from the indices not assigned yet, running a
+// Fisher-Yates shuffle one mint at a time. The pool is made of the positions from
+// minted_tokens_count up to the total token supply, and the SHUFFLED_INDICES dictionary holds
+// the index moved into a position, positions absent from it holding their own index. The pick
+// is seeded from the shuffle seed stored at installation and the deploy and block data.
+pub fn assign_shuffled_index(minted_tokens_count: u64, total_token_supply: u64) -> u64 {
+    let shuffle_seed = get_stored_value_with_user_errors::<String>(
+        SHUFFLE_SEED,
+        NFTCoreError::MissingShuffleSeed,
+        NFTCoreError::InvalidShuffleSeed,
+    );
+    let mut entropy = shuffle_seed.into_bytes();
+    entropy.extend_from_slice(&runtime::random_bytes());
+    entropy.extend_from_slice(&u64::from(runtime::get_blocktime()).to_le_bytes());
+    entropy.extend_from_slice(&minted_tokens_count.to_le_bytes());
+    let digest = runtime::blake2b(entropy);
+    let mut random = [0u8; 8];
+    random.copy_from_slice(&digest[..8]);
+
+    let position = minted_tokens_count
+        + u64::from_le_bytes(random) % (total_token_supply - minted_tokens_count);
+    let index_at = |position: u64| {
+        get_dictionary_value_from_key::<u64>(SHUFFLED_INDICES, &position.to_string())
+            .unwrap_or(position)
+    };
+    let token_index = index_at(position);
+    // The index at the front of the pool takes the place of the picked one.
+    upsert_dictionary_value_from_key(
+        SHUFFLED_INDICES,
+        &position.to_string(),
+        index_at(minted_tokens_count),
+    );
+    token_index
+}
+
 pub fn migrate_owned_tokens_in_ordinal_mode() {
     let current_number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
         NUMBER_OF_MINTED_TOKENS,
@@ -678,6 +1233,238 @@ pub fn get_reporting_mode() -> OwnerReverseLookupMode {
     .unwrap_or_revert()
 }
 
+// The metadata requirements of a single token. The kinds selected for a token are required for it
+// and the optional kinds it was not minted with are skipped, while tokens without a selection
+// follow the requirements of the collection.
+pub fn get_token_metadata_requirements(
+    metadata_kinds: BTreeMap<NFTMetadataKind, Requirement>,
+    token_metadata_kinds: Option<Vec<u8>>,
+) -> BTreeMap<NFTMetadataKind, Requirement> {
+    let token_metadata_kinds = match token_metadata_kinds {
+        Some(token_metadata_kinds) => token_metadata_kinds,
+        None => return metadata_kinds,
+    };
+    let mut requirements = BTreeMap::new();
+    for metadata_kind in token_metadata_kinds {
+        let metadata_kind = NFTMetadataKind::try_from(metadata_kind)
+            .unwrap_or_revert_with(NFTCoreError::InvalidTokenMetadataKinds);
+        match metadata_kinds.get(&metadata_kind) {
+            Some(Requirement::Required) | Some(Requirement::Optional) => {
+                requirements.insert(metadata_kind, Requirement::Required);
+            }
+            _ => runtime::revert(NFTCoreError::UnsupportedTokenMetadataKind),
+        }
+    }
+    for (metadata_kind, required) in metadata_kinds {
+        if required == Requirement::Required {
+            requirements.insert(metadata_kind, Requirement::Required);
+        }
+    }
+    requirements
+}
+
+// The metadata kinds selected for a token at mint, if any.
+pub fn get_token_metadata_kinds_selection(token_identifier: &TokenIdentifier) -> Option<Vec<u8>> {
+    get_dictionary_value_from_key::<Option<Vec<u8>>>(
+        TOKEN_METADATA_KINDS,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .flatten()
+}
+
+// Tokens minted without a selection have the required kinds of the collection, along with the
+// optional kinds their metadata is populated for.
+pub fn get_token_metadata_kinds(token_identifier: &TokenIdentifier) -> Vec<u8> {
+    if let Some(token_metadata_kinds) = get_token_metadata_kinds_selection(token_identifier) {
+        return token_metadata_kinds;
+    }
+    get_stored_value_with_user_errors::<BTreeMap<NFTMetadataKind, Requirement>>(
+        NFT_METADATA_KINDS,
+        NFTCoreError::MissingNFTMetadataKind,
+        NFTCoreError::InvalidNFTMetadataKind,
+    )
+    .into_iter()
+    .filter(|(metadata_kind, required)| match required {
+        Requirement::Required => true,
+        Requirement::Optional => is_token_metadata_populated(metadata_kind, token_identifier),
+        Requirement::Unneeded => false,
+    })
+    .map(|(metadata_kind, _)| metadata_kind as u8)
+    .collect()
+}
+
+fn is_token_metadata_populated(
+    metadata_kind: &NFTMetadataKind,
+    token_identifier: &TokenIdentifier,
+) -> bool {
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+    match metadata_kind {
+        // Token uris of the BaseURI kind are derived from the base uri rather than stored.
+        NFTMetadataKind::BaseURI => true,
+        NFTMetadataKind::Binary => get_dictionary_value_from_key::<Bytes>(
+            METADATA_BINARY,
+            &token_identifier_dictionary_key,
+        )
+        .map_or(false, |token_metadata| !token_metadata.is_empty()),
+        kind => get_dictionary_value_from_key::<String>(
+            &metadata::get_metadata_dictionary_name(kind),
+            &token_identifier_dictionary_key,
+        )
+        .map_or(false, |token_metadata| !token_metadata.is_empty()),
+    }
+}
+
+pub fn get_metadata_revision_count(token_identifier: &TokenIdentifier) -> u64 {
+    get_dictionary_value_from_key::<u64>(
+        METADATA_REVISION_COUNTS,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .unwrap_or_default()
+}
+
+pub fn get_metadata_revision_item_key(token_identifier: &TokenIdentifier, revision: u64) -> String {
+    format!("{}_{revision}", token_identifier.get_dictionary_item_key())
+}
+
+// The stored metadata of a token by kind, with Binary metadata base16 encoded. Token uris of the
+// BaseURI kind are derived from the base uri rather than stored, and are left out.
+pub fn get_stored_token_metadata(token_identifier: &TokenIdentifier) -> BTreeMap<u8, String> {
+    let token_identifier_dictionary_key = token_identifier.get_dictionary_item_key();
+    let mut stored_token_metadata: BTreeMap<u8, String> = BTreeMap::new();
+    for metadata_kind in get_token_metadata_kinds(token_identifier) {
+        let token_metadata = match NFTMetadataKind::try_from(metadata_kind).unwrap_or_revert() {
+            NFTMetadataKind::BaseURI => None,
+            NFTMetadataKind::Binary => get_dictionary_value_from_key::<Bytes>(
+                METADATA_BINARY,
+                &token_identifier_dictionary_key,
+            )
+            .map(|metadata_bytes| base16::encode_lower(metadata_bytes.as_slice())),
+            kind => get_dictionary_value_from_key::<String>(
+                &metadata::get_metadata_dictionary_name(&kind),
+                &token_identifier_dictionary_key,
+            ),
+        };
+        if let Some(token_metadata) = token_metadata {
+            stored_token_metadata.insert(metadata_kind, token_metadata);
+        }
+    }
+    stored_token_metadata
+}
+
+// Appends the stored metadata of the token to its history, along with the block time and the
+// account or contract which set it.
+pub fn record_metadata_revision(token_identifier: &TokenIdentifier, updater: Key) {
+    let revision = get_metadata_revision_count(token_identifier);
+    upsert_dictionary_value_from_key(
+        METADATA_HISTORY,
+        &get_metadata_revision_item_key(token_identifier, revision),
+        (
+            get_stored_token_metadata(token_identifier),
+            u64::from(runtime::get_blocktime()),
+            updater,
+        ),
+    );
+    upsert_dictionary_value_from_key(
+        METADATA_REVISION_COUNTS,
+        &token_identifier.get_dictionary_item_key(),
+        revision + 1u64,
+    );
+}
+
+// The fields of the collection metadata along with the runtime arguments setting them.
+const COLLECTION_METADATA_FIELDS: [(&str, &str); 4] = [
+    ("contract_uri", ARG_CONTRACT_URI),
+    ("description", ARG_COLLECTION_DESCRIPTION),
+    ("image", ARG_COLLECTION_IMAGE),
+    ("external_link", ARG_COLLECTION_EXTERNAL_LINK),
+];
+
+pub fn get_collection_metadata() -> BTreeMap<String, String> {
+    get_stored_value_with_user_errors::<BTreeMap<String, String>>(
+        COLLECTION_METADATA,
+        NFTCoreError::MissingCollectionMetadata,
+        NFTCoreError::InvalidCollectionMetadata,
+    )
+}
+
+// Sets the fields of the collection metadata passed as runtime arguments, missing fields default to
+// an empty string. Returns whether any field was passed.
+pub fn set_collection_metadata_from_runtime_args(
+    collection_metadata: &mut BTreeMap<String, String>,
+) -> bool {
+    let mut is_updated = false;
+    for (field, arg_name) in COLLECTION_METADATA_FIELDS.iter() {
+        let value = get_optional_named_arg_with_user_errors::<String>(
+            arg_name,
+            NFTCoreError::InvalidCollectionMetadata,
+        );
+        match value {
+            Some(value) => {
+                collection_metadata.insert(field.to_string(), value);
+                is_updated = true;
+            }
+            None => {
+                collection_metadata.entry(field.to_string()).or_default();
+            }
+        }
+    }
+    is_updated
+}
+
+pub fn get_base_uri() -> String {
+    get_stored_value_with_user_errors::<String>(
+        BASE_URI,
+        NFTCoreError::MissingBaseUri,
+        NFTCoreError::InvalidBaseUri,
+    )
+}
+
+// Contracts installed before delayed reveals reveal their metadata at mint.
+pub fn get_reveal_mode() -> RevealMode {
+    if !named_uref_exists(REVEAL_MODE) {
+        RevealMode::NoReveal
+    } else {
+        get_stored_value_with_user_errors::<u8>(
+            REVEAL_MODE,
+            NFTCoreError::MissingRevealMode,
+            NFTCoreError::InvalidRevealMode,
+        )
+        .try_into()
+        .unwrap_or_revert()
+    }
+}
+
+pub fn is_revealed() -> bool {
+    get_stored_value_with_user_errors::<bool>(
+        REVEALED,
+        NFTCoreError::MissingRevealed,
+        NFTCoreError::InvalidRevealed,
+    )
+}
+
+// The token uri of the BaseURI metadata kind, the base uri followed by the token identifier.
+// Until a delayed reveal happens every token points to the same placeholder uri.
+pub fn get_base_token_uri(token_identifier: &TokenIdentifier) -> String {
+    if get_reveal_mode() == RevealMode::Delayed && !is_revealed() {
+        return get_stored_value_with_user_errors::<String>(
+            PLACEHOLDER_URI,
+            NFTCoreError::MissingPlaceholderUri,
+            NFTCoreError::InvalidPlaceholderUri,
+        );
+    }
+    let mut token_uri = get_base_uri();
+    token_uri.push_str(&token_identifier.to_string());
+    token_uri
+}
+
+pub fn get_json_schema_version() -> u32 {
+    utils::get_stored_value_with_user_errors::<u32>(
+        JSON_SCHEMA_VERSION,
+        NFTCoreError::MissingJsonSchemaVersion,
+        NFTCoreError::InvalidJsonSchemaVersion,
+    )
+}
+
 pub fn add_page_entry_and_page_record(
     tokens_count: u64,
     item_key: &str,
@@ -805,19 +1592,39 @@ pub fn create_metadata_requirements(
     metadata_requirements
 }
 
-// Initializes events-releated named keys and records all event schemas.
-pub fn init_events() {
-    let schemas = Schemas::new()
+// The schemas of all the events emitted by the contract.
+fn events_schemas() -> Schemas {
+    Schemas::new()
         .with::<Mint>()
         .with::<Burn>()
+        .with::<ForcedBurn>()
         .with::<Approval>()
         .with::<ApprovalRevoked>()
         .with::<ApprovalForAll>()
         .with::<Transfer>()
+        .with::<ForcedTransfer>()
         .with::<MetadataUpdated>()
+        .with::<MetadataFrozen>()
         .with::<VariablesSet>()
-        .with::<Migration>();
-    casper_event_standard::init(schemas);
+        .with::<CollectionMetadataUpdated>()
+        .with::<Revealed>()
+        .with::<Migration>()
+}
+
+// Initializes events-releated named keys and records all event schemas.
+pub fn init_events() {
+    casper_event_standard::init(events_schemas());
+}
+
+// Contracts installed before the current events registered fewer or older schemas, which are
+// replaced so that the stored schemas describe the events emitted from now on.
+pub fn update_events_schemas() {
+    let events_schema_uref = get_uref(
+        casper_event_standard::EVENTS_SCHEMA,
+        NFTCoreError::MissingStorageUref,
+        NFTCoreError::InvalidStorageUref,
+    );
+    storage::write(events_schema_uref, events_schemas());
 }
 
 pub fn requires_rlo_migration() -> bool {
@@ -16,9 +16,9 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    api_error, contracts::NamedKeys, runtime_args, ApiError, CLType, ContractHash,
-    ContractPackageHash, ContractVersion, EntryPoint, EntryPointAccess, EntryPointType,
-    EntryPoints, Key, Parameter, RuntimeArgs, URef,
+    api_error, bytesrepr::Bytes, contracts::NamedKeys, runtime_args, ApiError, CLType,
+    ContractHash, ContractPackageHash, ContractVersion, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, URef,
 };
 
 const CONTRACT_NAME: &str = "minting_contract_hash";
@@ -31,6 +31,7 @@ const ENTRY_POINT_MINT: &str = "mint";
 const ENTRY_POINT_TRANSFER: &str = "transfer";
 const ENTRY_POINT_BURN: &str = "burn";
 const ENTRY_POINT_METADATA: &str = "metadata";
+const ENTRY_POINT_METADATA_BYTES: &str = "metadata_bytes";
 const ENTRY_POINT_REGISTER_OWNER: &str = "register_owner";
 const ENTRY_POINT_APPROVE: &str = "approve";
 const ENTRY_POINT_REVOKE: &str = "revoke";
@@ -205,6 +206,24 @@ pub extern "C" fn metadata() {
     runtime::put_key("metadata", storage::new_uref(metadata).into());
 }
 
+#[no_mangle]
+pub extern "C" fn metadata_bytes() {
+    let nft_contract_hash: ContractHash = runtime::get_named_arg::<Key>(ARG_NFT_CONTRACT_HASH)
+        .into_hash()
+        .map(ContractHash::new)
+        .unwrap();
+
+    let token_id = runtime::get_named_arg::<u64>(ARG_TOKEN_ID);
+    let metadata_bytes = runtime::call_contract::<Bytes>(
+        nft_contract_hash,
+        ENTRY_POINT_METADATA_BYTES,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id
+        },
+    );
+    runtime::put_key("metadata_bytes", storage::new_uref(metadata_bytes).into());
+}
+
 #[no_mangle]
 pub extern "C" fn register_contract() {
     let nft_contract_hash: ContractHash = runtime::get_named_arg::<Key>(ARG_NFT_CONTRACT_HASH)
@@ -308,6 +327,14 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     );
+
+    let metadata_bytes_entry_point = EntryPoint::new(
+        ENTRY_POINT_METADATA_BYTES,
+        vec![Parameter::new(ARG_TOKEN_ID, CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(mint_entry_point);
     entry_points.add_entry_point(transfer_entry_point);
@@ -315,6 +342,7 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(revoke_entry_point);
     entry_points.add_entry_point(burn_entry_point);
     entry_points.add_entry_point(metadata_entry_point);
+    entry_points.add_entry_point(metadata_bytes_entry_point);
     entry_points
 }
 
@@ -1,12 +1,16 @@
+use std::collections::BTreeMap;
+
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP, CONTRACT_NAME,
+        ACCOUNT_USER_1, ARG_ENTRY_POINT, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP, CONTRACT_NAME,
         MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
-        TEST_PRETTY_721_META_DATA,
+        SUPPLY_STATISTICS_SESSION_WASM, TEST_PRETTY_721_META_DATA,
+        TEST_PRETTY_UPDATED_721_META_DATA,
     },
     installer_request_builder::{
-        BurnMode, InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode,
-        NFTIdentifierMode, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
+        BurnMode, BurnStorageMode, HashIdentifierMode, InstallerRequestBuilder, MetadataMutability,
+        MintingMode, NFTHolderMode, NFTIdentifierMode, NFTMetadataKind, OwnerReverseLookupMode,
+        OwnershipMode, WhitelistMode,
     },
     support::{
         self, get_dictionary_value_from_key, get_minting_contract_hash,
@@ -17,12 +21,19 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_types::{runtime_args, Key, RuntimeArgs};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
 use contract::{
     constants::{
-        ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_OPERATOR, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNT_TOKENS, BURN_MODE, ENTRY_POINT_BURN,
-        ENTRY_POINT_MINT, ENTRY_POINT_SET_APPROVALL_FOR_ALL, TOKEN_COUNT,
+        ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_OPERATOR, ARG_SOURCE_KEY, ARG_TARGET_KEY,
+        ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNT_TOKENS,
+        BURN_MODE, ENTRY_POINT_BURN, ENTRY_POINT_CIRCULATING_SUPPLY, ENTRY_POINT_FREEZE_METADATA,
+        ENTRY_POINT_MINT, ENTRY_POINT_NUMBER_OF_BURNT_TOKENS, ENTRY_POINT_NUMBER_OF_HOLDERS,
+        ENTRY_POINT_NUMBER_OF_MINTED_TOKENS, ENTRY_POINT_OWNER_OF,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_SET_TOKEN_METADATA,
+        ENTRY_POINT_TOTAL_TOKEN_SUPPLY, ENTRY_POINT_TRANSFER, FROZEN_METADATA, HASH_BY_INDEX,
+        INDEX_BY_HASH, ISSUED_TOKEN_COUNTS, METADATA_HISTORY, METADATA_NFT721,
+        METADATA_REVISION_COUNTS, NUMBER_OF_BURNT_TOKENS, NUMBER_OF_HOLDERS, REMINTED_TOKENS,
+        TOKEN_COUNT, TOKEN_OWNERS,
     },
     events::events_ces::Burn,
     modalities::TokenIdentifier,
@@ -133,7 +144,12 @@ fn should_burn_minted_token(reporting: OwnerReverseLookupMode) {
     assert_eq!(actual_balance, expected_balance);
 
     // Expect Burn event.
-    let expected_event = Burn::new(token_owner, TokenIdentifier::Index(token_id), token_owner);
+    let expected_event = Burn::new(
+        token_owner,
+        TokenIdentifier::Index(token_id),
+        token_owner,
+        None,
+    );
     let actual_event: Burn = support::get_event(&builder, &nft_contract_key, 1).unwrap();
     assert_eq!(actual_event, expected_event, "Expected Burn event.");
 }
@@ -582,7 +598,7 @@ fn should_let_account_operator_burn_tokens_with_operator_burn_mode() {
 
     let burner = Key::from(operator); // Burner is operator account
 
-    let expected_event = Burn::new(token_owner, TokenIdentifier::Index(token_id), burner);
+    let expected_event = Burn::new(token_owner, TokenIdentifier::Index(token_id), burner, None);
     assert_eq!(actual_event, expected_event, "Expected Burn event.");
 }
 
@@ -709,7 +725,7 @@ fn should_let_contract_operator_burn_tokens_with_operator_burn_mode() {
 
     let burner = Key::from(minting_contract_hash); // Burner is contract not session caller ACCOUNT_USER_1
 
-    let expected_event = Burn::new(token_owner, TokenIdentifier::Index(token_id), burner);
+    let expected_event = Burn::new(token_owner, TokenIdentifier::Index(token_id), burner, None);
     assert_eq!(actual_event, expected_event, "Expected Burn event.");
 }
 
@@ -838,7 +854,7 @@ fn should_let_package_operator_burn_tokens_with_contract_package_mode_and_operat
 
     let burner = Key::from(minting_contract_hash); // Burner is contract not its package nor session caller ACCOUNT_USER_1
 
-    let expected_event = Burn::new(token_owner, TokenIdentifier::Index(token_id), burner);
+    let expected_event = Burn::new(token_owner, TokenIdentifier::Index(token_id), burner, None);
     assert_eq!(actual_event, expected_event, "Expected Burn event.");
 }
 
@@ -891,3 +907,440 @@ fn should_burn_token_in_hash_identifier_mode() {
 
     builder.exec(burn_request).expect_success().commit();
 }
+
+#[test]
+fn should_maintain_supply_statistics_on_mint_and_burn() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(100u64)
+            .with_ownership_mode(OwnershipMode::Transferable)
+            .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+            .build();
+
+    builder
+        .exec(install_request_builder)
+        .expect_success()
+        .commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    for token_owner in [
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(AccountHash::new(ACCOUNT_USER_1)),
+    ] {
+        let minting_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => token_owner,
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(minting_request).expect_success().commit();
+    }
+
+    let number_of_holders = support::query_stored_value::<u64>(
+        &builder,
+        nft_contract_key,
+        vec![NUMBER_OF_HOLDERS.to_string()],
+    );
+    assert_eq!(number_of_holders, 2u64);
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    let number_of_burnt_tokens = support::query_stored_value::<u64>(
+        &builder,
+        nft_contract_key,
+        vec![NUMBER_OF_BURNT_TOKENS.to_string()],
+    );
+    assert_eq!(number_of_burnt_tokens, 1u64);
+
+    // The default account still holds a token.
+    let number_of_holders = support::query_stored_value::<u64>(
+        &builder,
+        nft_contract_key,
+        vec![NUMBER_OF_HOLDERS.to_string()],
+    );
+    assert_eq!(number_of_holders, 2u64);
+
+    // Transferring its last token to an existing holder leaves a single holder.
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_SOURCE_KEY => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TARGET_KEY => Key::Account(AccountHash::new(ACCOUNT_USER_1)),
+            ARG_TOKEN_ID => 1u64,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let number_of_holders = support::query_stored_value::<u64>(
+        &builder,
+        nft_contract_key,
+        vec![NUMBER_OF_HOLDERS.to_string()],
+    );
+    assert_eq!(number_of_holders, 1u64);
+
+    for (entry_point, expected_statistic) in [
+        (ENTRY_POINT_TOTAL_TOKEN_SUPPLY, 100u64),
+        (ENTRY_POINT_NUMBER_OF_MINTED_TOKENS, 3u64),
+        (ENTRY_POINT_NUMBER_OF_BURNT_TOKENS, 1u64),
+        (ENTRY_POINT_CIRCULATING_SUPPLY, 2u64),
+        (ENTRY_POINT_NUMBER_OF_HOLDERS, 1u64),
+    ] {
+        let statistic = support::call_session_code_with_ret::<u64>(
+            &mut builder,
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_key,
+            runtime_args! {
+                ARG_ENTRY_POINT => entry_point.to_string(),
+            },
+            SUPPLY_STATISTICS_SESSION_WASM,
+            entry_point,
+        );
+        assert_eq!(statistic, expected_statistic, "unexpected {entry_point}");
+    }
+}
+
+#[test]
+fn should_reclaim_burnt_token_storage_and_reuse_its_hash() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request_in_ordinal_mode =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(100u64)
+            .with_burn_storage_mode(BurnStorageMode::ReclaimAndReuse)
+            .build();
+
+    builder
+        .exec(install_request_in_ordinal_mode)
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        error,
+        243u16,
+        "ordinal indices cannot be reused and must raise InvalidBurnStorageMode",
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_identifier_mode(NFTIdentifierMode::Hash)
+        .with_metadata_mutability(MetadataMutability::Immutable)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_burn_storage_mode(BurnStorageMode::ReclaimAndReuse)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_hash: String =
+        base16::encode_lower(&support::create_blake2b_hash(TEST_PRETTY_721_META_DATA));
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    let burnt_metadata = get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        METADATA_NFT721,
+        &token_hash,
+    );
+    assert_eq!(burnt_metadata, "");
+
+    let burnt_hash =
+        get_dictionary_value_from_key::<String>(&builder, &nft_contract_key, HASH_BY_INDEX, "0");
+    assert_eq!(burnt_hash, "");
+
+    // The burnt hash can be minted again as a new token.
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let is_reminted = get_dictionary_value_from_key::<bool>(
+        &builder,
+        &nft_contract_key,
+        REMINTED_TOKENS,
+        &token_hash,
+    );
+    assert!(is_reminted);
+
+    let reminted_index = get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        INDEX_BY_HASH,
+        &token_hash,
+    );
+    assert_eq!(reminted_index, 1u64);
+
+    // The reminted hash is already listed among the tokens issued by the default account.
+    let issued_token_count = get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        ISSUED_TOKEN_COUNTS,
+        &DEFAULT_ACCOUNT_ADDR.to_string(),
+    );
+    assert_eq!(issued_token_count, 1u64);
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+}
+
+#[test]
+fn should_mint_a_reused_hash_with_a_clean_metadata_state() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+        .with_identifier_mode(NFTIdentifierMode::Hash)
+        .with_hash_identifier_mode(HashIdentifierMode::Custom)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_burn_storage_mode(BurnStorageMode::ReclaimAndReuse)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_hash = "SKU-0001".to_string();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_TOKEN_HASH => token_hash.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let update_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(update_request).expect_success().commit();
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FREEZE_METADATA,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(freeze_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    let is_frozen = get_dictionary_value_from_key::<bool>(
+        &builder,
+        &nft_contract_key,
+        FROZEN_METADATA,
+        &token_hash,
+    );
+    assert!(!is_frozen);
+
+    let revision_count = get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        METADATA_REVISION_COUNTS,
+        &token_hash,
+    );
+    assert_eq!(revision_count, 0u64);
+
+    // The owner of the burnt token is cleared along with the rest of its state.
+    let owner_of_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_OWNER_OF,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(owner_of_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        error,
+        42u16,
+        "the owner of a reclaimed token must raise PreviouslyBurntToken",
+    );
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(
+        error,
+        42u16,
+        "burning a reclaimed token again must raise PreviouslyBurntToken",
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_TOKEN_HASH => token_hash.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let reminted_metadata = get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        METADATA_NFT721,
+        &token_hash,
+    );
+
+    // The reminted token is neither frozen nor does it inherit the history of the burnt token.
+    let update_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(update_request).expect_success().commit();
+
+    let revision_count = get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        METADATA_REVISION_COUNTS,
+        &token_hash,
+    );
+    assert_eq!(revision_count, 2u64);
+
+    let (minted_metadata, _, _) = get_dictionary_value_from_key::<(BTreeMap<u8, String>, u64, Key)>(
+        &builder,
+        &nft_contract_key,
+        METADATA_HISTORY,
+        &format!("{token_hash}_0"),
+    );
+    assert_eq!(
+        minted_metadata,
+        BTreeMap::from([(NFTMetadataKind::NFT721 as u8, reminted_metadata)])
+    );
+
+    let token_owner = get_dictionary_value_from_key::<Key>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_hash,
+    );
+    assert_eq!(token_owner, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+}
@@ -244,7 +244,7 @@ fn should_record_cep47_dictionary_style_metadata_update_event_for_nft721_using_t
     let original_metadata = match &nft_metadata_kind {
         NFTMetadataKind::CEP78 => TEST_PRETTY_CEP78_METADATA,
         NFTMetadataKind::NFT721 => TEST_PRETTY_721_META_DATA,
-        NFTMetadataKind::Raw => "",
+        NFTMetadataKind::Raw | NFTMetadataKind::BaseURI | NFTMetadataKind::Binary => "",
         NFTMetadataKind::CustomValidated => &custom_metadata,
     };
 
@@ -267,6 +267,8 @@ fn should_record_cep47_dictionary_style_metadata_update_event_for_nft721_using_t
         NFTMetadataKind::NFT721 => METADATA_NFT721,
         NFTMetadataKind::Raw => METADATA_RAW,
         NFTMetadataKind::CustomValidated => METADATA_CUSTOM_VALIDATED,
+        NFTMetadataKind::BaseURI => panic!("token uris of the BaseURI kind are not stored"),
+        NFTMetadataKind::Binary => panic!("binary metadata is not stored as a string"),
     };
 
     let actual_metadata = match identifier_mode {
@@ -292,7 +294,7 @@ fn should_record_cep47_dictionary_style_metadata_update_event_for_nft721_using_t
     let updated_metadata = match &nft_metadata_kind {
         NFTMetadataKind::CEP78 => TEST_PRETTY_UPDATED_CEP78_METADATA,
         NFTMetadataKind::NFT721 => TEST_PRETTY_UPDATED_721_META_DATA,
-        NFTMetadataKind::Raw => "",
+        NFTMetadataKind::Raw | NFTMetadataKind::BaseURI | NFTMetadataKind::Binary => "",
         NFTMetadataKind::CustomValidated => &custom_updated_metadata,
     };
 
@@ -11,8 +11,9 @@ use contract::{
         ENTRY_POINT_INIT, NUMBER_OF_MINTED_TOKENS,
     },
     events::events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
-        Transfer, VariablesSet,
+        Approval, ApprovalForAll, ApprovalRevoked, Burn, CollectionMetadataUpdated, ForcedBurn,
+        ForcedTransfer, MetadataFrozen, MetadataUpdated, Migration, Mint, Revealed, Transfer,
+        VariablesSet,
     },
 };
 
@@ -112,12 +113,17 @@ fn should_install_contract() {
     let expected_schemas = Schemas::new()
         .with::<Mint>()
         .with::<Burn>()
+        .with::<ForcedBurn>()
         .with::<Approval>()
         .with::<ApprovalRevoked>()
         .with::<ApprovalForAll>()
         .with::<Transfer>()
+        .with::<ForcedTransfer>()
         .with::<MetadataUpdated>()
+        .with::<MetadataFrozen>()
         .with::<VariablesSet>()
+        .with::<CollectionMetadataUpdated>()
+        .with::<Revealed>()
         .with::<Migration>();
     let actual_schemas: Schemas = support::query_stored_value(
         &builder,
@@ -1,31 +1,45 @@
 use core::panic;
+use std::collections::BTreeMap;
 
 use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
-use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, Key, RuntimeArgs,
+};
 use contract::{
     constants::{
-        ACL_WHITELIST, ARG_COLLECTION_NAME, ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA,
-        ARG_TOKEN_OWNER, ENTRY_POINT_METADATA, ENTRY_POINT_MINT, ENTRY_POINT_SET_TOKEN_METADATA,
-        METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW, TOKEN_OWNERS,
+        ACL_WHITELIST, ARG_BASE_URI, ARG_COLLECTION_NAME, ARG_JSON_SCHEMA, ARG_METADATA_EDITOR,
+        ARG_REVISION, ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_METADATA_KINDS, ARG_TOKEN_META_DATA,
+        ARG_TOKEN_META_DATA_BYTES, ARG_TOKEN_OWNER, BASE_URI, ENTRY_POINT_FREEZE_METADATA,
+        ENTRY_POINT_METADATA, ENTRY_POINT_METADATA_AT, ENTRY_POINT_METADATA_BYTES,
+        ENTRY_POINT_MINT, ENTRY_POINT_REVEAL, ENTRY_POINT_SET_JSON_SCHEMA,
+        ENTRY_POINT_SET_TOKEN_METADATA, ENTRY_POINT_SET_VARIABLES,
+        ENTRY_POINT_TOKEN_METADATA_KINDS, JSON_SCHEMAS, JSON_SCHEMA_VERSION, METADATA_BINARY,
+        METADATA_CEP78, METADATA_CUSTOM_VALIDATED, METADATA_HISTORY, METADATA_NFT721, METADATA_RAW,
+        METADATA_REVISION_COUNTS, REVEALED, TOKEN_METADATA_KINDS, TOKEN_OWNERS,
+        TOKEN_SCHEMA_VERSIONS,
     },
-    events::events_ces::MetadataUpdated,
+    events::events_ces::{MetadataFrozen, MetadataUpdated, Revealed},
     modalities::TokenIdentifier,
 };
 
 use crate::utility::{
     constants::{
-        ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP,
-        MALFORMED_META_DATA, MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
-        NFT_TEST_COLLECTION, TEST_PRETTY_721_META_DATA, TEST_PRETTY_CEP78_METADATA,
-        TEST_PRETTY_UPDATED_721_META_DATA, TEST_PRETTY_UPDATED_CEP78_METADATA, TOKEN_HASH,
+        ACCOUNT_USER_1, ACCOUNT_USER_2, ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH,
+        ARG_REVERSE_LOOKUP, MALFORMED_META_DATA, MINTING_CONTRACT_WASM, MINT_SESSION_WASM,
+        NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, TEST_PRETTY_721_META_DATA,
+        TEST_PRETTY_CEP78_METADATA, TEST_PRETTY_UPDATED_721_META_DATA,
+        TEST_PRETTY_UPDATED_CEP78_METADATA, TOKEN_HASH,
     },
     installer_request_builder::{
-        InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
-        TEST_CUSTOM_METADATA, TEST_CUSTOM_METADATA_SCHEMA, TEST_CUSTOM_UPDATED_METADATA,
+        HashIdentifierMode, InstallerRequestBuilder, MetadataMutability, MetadataStorageFormat,
+        MetadataUpdateMode, MintingMode, NFTHolderMode, NFTIdentifierMode, NFTMetadataKind,
+        OwnerReverseLookupMode, OwnershipMode, RevealMode, WhitelistMode, TEST_CUSTOM_METADATA,
+        TEST_CUSTOM_METADATA_SCHEMA, TEST_CUSTOM_UPDATED_METADATA,
     },
     support,
     support::{assert_expected_error, get_minting_contract_hash, get_nft_contract_hash},
@@ -72,7 +86,7 @@ fn should_prevent_update_in_immutable_mode() {
         support::get_nft_contract_hash(&builder),
         ENTRY_POINT_SET_TOKEN_METADATA,
         runtime_args! {
-            ARG_TOKEN_HASH => token_hash,
+            ARG_TOKEN_HASH => token_hash.clone(),
             ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_721_META_DATA
         },
     )
@@ -82,7 +96,16 @@ fn should_prevent_update_in_immutable_mode() {
 
     let error = builder.get_error().expect("must have error");
 
-    support::assert_expected_error(error, 104, "must match ForbiddenMetadataUpdate(104)")
+    support::assert_expected_error(error, 104, "must match ForbiddenMetadataUpdate(104)");
+
+    // The minted metadata is recorded even though it cannot be updated.
+    let revision_count = support::get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        METADATA_REVISION_COUNTS,
+        &token_hash,
+    );
+    assert_eq!(revision_count, 1u64);
 }
 
 #[test]
@@ -106,6 +129,67 @@ fn should_prevent_install_with_hash_identifier_in_mutable_mode() {
     assert_expected_error(error, 102, "Should raise InvalidMetadataMutability(102)")
 }
 
+#[test]
+fn should_allow_update_of_custom_hash_identified_tokens_in_mutable_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+        .with_identifier_mode(NFTIdentifierMode::Hash)
+        .with_hash_identifier_mode(HashIdentifierMode::Custom)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+    let token_hash = "SKU-0042".to_string();
+
+    let mint_token_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_TOKEN_HASH => token_hash.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_token_request).expect_success().commit();
+
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        support::get_nft_contract_hash(&builder),
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_721_META_DATA
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_token_metadata_request)
+        .expect_success()
+        .commit();
+
+    let updated_metadata = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        METADATA_NFT721,
+        &token_hash,
+    );
+    assert_eq!(updated_metadata, TEST_PRETTY_UPDATED_721_META_DATA);
+}
+
 #[test]
 fn should_prevent_update_for_invalid_metadata() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -264,7 +348,7 @@ fn should_allow_update_for_valid_metadata_based_on_kind(
     let original_metadata = match &nft_metadata_kind {
         NFTMetadataKind::CEP78 => TEST_PRETTY_CEP78_METADATA,
         NFTMetadataKind::NFT721 => TEST_PRETTY_721_META_DATA,
-        NFTMetadataKind::Raw => "",
+        NFTMetadataKind::Raw | NFTMetadataKind::BaseURI | NFTMetadataKind::Binary => "",
         NFTMetadataKind::CustomValidated => &custom_metadata,
     };
 
@@ -287,6 +371,8 @@ fn should_allow_update_for_valid_metadata_based_on_kind(
         NFTMetadataKind::NFT721 => METADATA_NFT721,
         NFTMetadataKind::Raw => METADATA_RAW,
         NFTMetadataKind::CustomValidated => METADATA_CUSTOM_VALIDATED,
+        NFTMetadataKind::BaseURI => panic!("token uris of the BaseURI kind are not stored"),
+        NFTMetadataKind::Binary => panic!("binary metadata is not stored as a string"),
     };
 
     let token_hash = base16::encode_lower(&support::create_blake2b_hash(original_metadata));
@@ -314,7 +400,7 @@ fn should_allow_update_for_valid_metadata_based_on_kind(
     let updated_metadata = match &nft_metadata_kind {
         NFTMetadataKind::CEP78 => TEST_PRETTY_UPDATED_CEP78_METADATA,
         NFTMetadataKind::NFT721 => TEST_PRETTY_UPDATED_721_META_DATA,
-        NFTMetadataKind::Raw => "",
+        NFTMetadataKind::Raw | NFTMetadataKind::BaseURI | NFTMetadataKind::Binary => "",
         NFTMetadataKind::CustomValidated => &custom_updated_metadata,
     };
 
@@ -983,3 +1069,1877 @@ fn should_not_require_json_schema_when_kind_is_not_custom_validated() {
     should_not_require_json_schema_when_kind_is(NFTMetadataKind::CEP78);
     should_not_require_json_schema_when_kind_is(NFTMetadataKind::NFT721);
 }
+
+const TEST_CONSTRAINED_JSON_SCHEMA: &str = r#"{
+    "properties": {
+        "deity_name": {
+            "name": "deity_name",
+            "description": "The name of deity from a particular pantheon.",
+            "required": true,
+            "type": "string",
+            "min_length": 3,
+            "max_length": 16,
+            "pattern": "^[A-Z][a-z]+$"
+        },
+        "mythology": {
+            "name": "mythology",
+            "description": "The mythology the deity belongs to.",
+            "required": true,
+            "type": "string",
+            "enum": ["Nordic", "Greek", "Egyptian"]
+        }
+    }
+}"#;
+
+#[test]
+fn should_validate_custom_metadata_against_schema_constraints() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(TEST_CONSTRAINED_JSON_SCHEMA.to_string())
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => r#"{"deity_name":"Baldur","mythology":"Nordic"}"#.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let invalid_metadata = [
+        // Does not match the pattern.
+        r#"{"deity_name":"baldur","mythology":"Nordic"}"#,
+        // Shorter than min_length.
+        r#"{"deity_name":"Ra","mythology":"Egyptian"}"#,
+        // Longer than max_length.
+        r#"{"deity_name":"Quetzalcoatlcoatl","mythology":"Greek"}"#,
+        // Not one of the enum values.
+        r#"{"deity_name":"Quetzalcoatl","mythology":"Aztec"}"#,
+    ];
+
+    for metadata in invalid_metadata {
+        let mint_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => metadata.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_failure();
+        let error = builder.get_error().expect("must have error");
+
+        assert_expected_error(
+            error,
+            95u16,
+            "metadata violating the schema constraints must raise InvalidCustomMetadata",
+        );
+    }
+}
+
+#[test]
+fn should_prevent_install_with_unsupported_schema_pattern() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let json_schema = TEST_CONSTRAINED_JSON_SCHEMA.replace("^[A-Z][a-z]+$", "^(Baldur|Odin)$");
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(json_schema)
+        .build();
+
+    builder.exec(install_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        68u16,
+        "schema with an unsupported pattern must raise InvalidJsonSchema",
+    );
+}
+
+#[test]
+fn should_match_patterns_with_adjacent_quantifiers_in_bounded_time() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    // Without memoisation, matching this pattern against a long run of `a` without a trailing `b`
+    // backtracks exponentially and runs out of gas.
+    let json_schema = TEST_CONSTRAINED_JSON_SCHEMA
+        .replace("^[A-Z][a-z]+$", "^a*a*a*a*a*a*a*a*b$")
+        .replace("\"max_length\": 16", "\"max_length\": 64");
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(json_schema)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => r#"{"deity_name":"aaaab","mythology":"Nordic"}"#.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let metadata = format!(
+        r#"{{"deity_name":"{}","mythology":"Nordic"}}"#,
+        "a".repeat(60)
+    );
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => metadata,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        95u16,
+        "a value not matching the pattern must raise InvalidCustomMetadata",
+    );
+}
+
+#[test]
+fn should_store_nested_and_non_string_custom_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let json_schema = r#"{
+        "properties": {
+            "name": {
+                "name": "name",
+                "description": "The name of the NFT.",
+                "required": true,
+                "type": "string"
+            },
+            "level": {
+                "name": "level",
+                "description": "The level of the character.",
+                "required": true,
+                "type": "number"
+            },
+            "attributes": {
+                "name": "attributes",
+                "description": "The traits of the character.",
+                "required": true,
+                "type": "array",
+                "properties": {
+                    "trait_type": {
+                        "name": "trait_type",
+                        "description": "The name of the trait.",
+                        "required": true,
+                        "type": "string"
+                    },
+                    "value": {
+                        "name": "value",
+                        "description": "The value of the trait.",
+                        "required": true
+                    }
+                }
+            }
+        }
+    }"#;
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(json_schema.to_string())
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    let custom_metadata = serde_json::json!({
+        "name": "Baldur",
+        "level": 7,
+        "attributes": [
+            { "trait_type": "mythology", "value": "Nordic" },
+            { "trait_type": "strength", "value": 42 },
+            { "trait_type": "immortal", "value": false }
+        ]
+    });
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => custom_metadata.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let actual_metadata = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        METADATA_CUSTOM_VALIDATED,
+        &0u64.to_string(),
+    );
+
+    let actual_metadata: serde_json::Value =
+        serde_json::from_str(&actual_metadata).expect("stored metadata must be valid json");
+    assert_eq!(actual_metadata, custom_metadata);
+
+    // An attribute item missing a required nested property must be rejected.
+    let invalid_metadata = serde_json::json!({
+        "name": "Loki",
+        "level": 3,
+        "attributes": [{ "value": "Nordic" }]
+    });
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => invalid_metadata.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        95u16,
+        "nested metadata violating the schema must raise InvalidCustomMetadata",
+    );
+}
+
+#[test]
+fn should_validate_custom_metadata_against_newest_json_schema_version() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let original_json_schema =
+        serde_json::to_string(&*TEST_CUSTOM_METADATA_SCHEMA).expect("must convert to json schema");
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(original_json_schema.clone())
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let custom_metadata = serde_json::to_string_pretty(&*TEST_CUSTOM_METADATA)
+        .expect("must convert to json metadata");
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => custom_metadata.clone(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_schema_version: Option<u32> = support::get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_SCHEMA_VERSIONS,
+        &0u64.to_string(),
+    );
+    assert_eq!(token_schema_version, Some(0u32));
+
+    // The next season requires every deity to have an enemy.
+    let updated_json_schema = r#"{
+        "properties": {
+            "deity_name": {
+                "name": "deity_name",
+                "description": "The name of deity from a particular pantheon.",
+                "required": true
+            },
+            "mythology": {
+                "name": "mythology",
+                "description": "The mythology the deity belongs to.",
+                "required": true
+            },
+            "enemy": {
+                "name": "enemy",
+                "description": "The sworn enemy of the deity.",
+                "required": true
+            }
+        }
+    }"#;
+
+    let set_json_schema_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_JSON_SCHEMA,
+        runtime_args! {
+            ARG_JSON_SCHEMA => updated_json_schema.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(set_json_schema_request)
+        .expect_success()
+        .commit();
+
+    let json_schema_version: u32 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![JSON_SCHEMA_VERSION.to_string()],
+    );
+    assert_eq!(json_schema_version, 1u32);
+
+    let first_json_schema: String = support::get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        JSON_SCHEMAS,
+        &0u32.to_string(),
+    );
+    assert_eq!(first_json_schema, original_json_schema);
+
+    // Metadata valid under the first version no longer satisfies the newest one.
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => custom_metadata,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        95u16,
+        "metadata must be validated against the newest json schema",
+    );
+
+    let custom_updated_metadata = serde_json::to_string_pretty(&*TEST_CUSTOM_UPDATED_METADATA)
+        .expect("must convert to json metadata");
+
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => custom_updated_metadata
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_token_metadata_request)
+        .expect_success()
+        .commit();
+
+    let token_schema_version: Option<u32> = support::get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_SCHEMA_VERSIONS,
+        &0u64.to_string(),
+    );
+    assert_eq!(token_schema_version, Some(1u32));
+}
+
+#[test]
+fn should_prevent_json_schema_update_by_non_installer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(
+            serde_json::to_string(&*TEST_CUSTOM_METADATA_SCHEMA)
+                .expect("must convert to json schema"),
+        )
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let account_user_1 = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let set_json_schema_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        support::get_nft_contract_hash(&builder),
+        ENTRY_POINT_SET_JSON_SCHEMA,
+        runtime_args! {
+            ARG_JSON_SCHEMA => serde_json::to_string(&*TEST_CUSTOM_METADATA_SCHEMA)
+                .expect("must convert to json schema")
+        },
+    )
+    .build();
+
+    builder.exec(set_json_schema_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        1u16,
+        "only the installer can publish a new json schema",
+    );
+}
+
+#[test]
+fn should_prevent_update_of_locked_metadata_field() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CEP78)
+        .with_locked_metadata_fields(vec!["name".to_string()])
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_CEP78_METADATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    // The token_uri and checksum are mutable while the name stays the same.
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_CEP78_METADATA.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_token_metadata_request)
+        .expect_success()
+        .commit();
+
+    let renamed_metadata = TEST_PRETTY_UPDATED_CEP78_METADATA.replace("John Doe", "Jane Doe");
+
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => renamed_metadata
+        },
+    )
+    .build();
+
+    builder.exec(update_token_metadata_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        182u16,
+        "changing a locked metadata field must raise LockedMetadataField",
+    );
+}
+
+#[test]
+fn should_prevent_update_of_locked_field_within_array_items() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let json_schema = r#"{
+        "properties": {
+            "attributes": {
+                "name": "attributes",
+                "description": "The traits of the character.",
+                "required": true,
+                "type": "array",
+                "properties": {
+                    "trait_type": {
+                        "name": "trait_type",
+                        "description": "The name of the trait.",
+                        "required": true,
+                        "mutable": false,
+                        "type": "string"
+                    },
+                    "value": {
+                        "name": "value",
+                        "description": "The value of the trait.",
+                        "required": true
+                    }
+                }
+            }
+        }
+    }"#;
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CustomValidated)
+        .with_json_schema(json_schema.to_string())
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA =>
+                r#"{"attributes":[{"trait_type":"strength","value":3}]}"#.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    // The value of an item is mutable while its trait_type stays the same.
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA =>
+                r#"{"attributes":[{"trait_type":"strength","value":4}]}"#.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_token_metadata_request)
+        .expect_success()
+        .commit();
+
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA =>
+                r#"{"attributes":[{"trait_type":"agility","value":4}]}"#.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(update_token_metadata_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        182u16,
+        "changing a locked field of an array item must raise LockedMetadataField",
+    );
+}
+
+#[test]
+fn should_prevent_install_with_unknown_locked_metadata_field() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_nft_metadata_kind(NFTMetadataKind::CEP78)
+        .with_locked_metadata_fields(vec!["description".to_string()])
+        .build();
+
+    builder.exec(install_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        184u16,
+        "locking a field unknown to the built-in schemas must raise InvalidLockedMetadataFields",
+    );
+}
+
+#[test]
+fn should_prevent_metadata_update_once_frozen() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CEP78)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_CEP78_METADATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_id = 0u64;
+
+    // Only the token owner or the installer can freeze the metadata.
+    let account_user_1 = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let unauthorized_freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_FREEZE_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+        },
+    )
+    .build();
+
+    builder.exec(unauthorized_freeze_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        6u16,
+        "freezing metadata by another account must raise InvalidTokenOwner",
+    );
+
+    let freeze_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FREEZE_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+        },
+    )
+    .build();
+
+    builder.exec(freeze_request).expect_success().commit();
+
+    // Expect MetadataFrozen event.
+    let expected_event = MetadataFrozen::new(TokenIdentifier::Index(token_id));
+    let actual_event: MetadataFrozen = support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected MetadataFrozen event."
+    );
+
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_CEP78_METADATA.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(update_token_metadata_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        185u16,
+        "updating frozen metadata must raise FrozenMetadata",
+    );
+}
+
+#[test]
+fn should_allow_metadata_editor_to_update_metadata_in_delegated_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let metadata_editor = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let other_account = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_metadata_update_mode(MetadataUpdateMode::Delegated)
+        .with_metadata_editor(Key::Account(metadata_editor))
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CEP78)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_CEP78_METADATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_id = 0u64;
+
+    let update_by_other_account_request = ExecuteRequestBuilder::contract_call_by_hash(
+        other_account,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_CEP78_METADATA.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_by_other_account_request)
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        6u16,
+        "updating metadata by an unrelated account must raise InvalidTokenOwner",
+    );
+
+    let update_by_editor_request = ExecuteRequestBuilder::contract_call_by_hash(
+        metadata_editor,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_CEP78_METADATA.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_by_editor_request)
+        .expect_success()
+        .commit();
+
+    let actual_metadata = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        METADATA_CEP78,
+        &token_id.to_string(),
+    );
+
+    assert_eq!(actual_metadata, TEST_PRETTY_UPDATED_CEP78_METADATA);
+
+    // The token owner can still update the metadata.
+    let update_by_owner_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_CEP78_METADATA.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_by_owner_request)
+        .expect_success()
+        .commit();
+
+    // Passing None through set_variables removes the metadata editor.
+    let remove_editor_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_METADATA_EDITOR => Option::<Key>::None
+        },
+    )
+    .build();
+
+    builder
+        .exec(remove_editor_request)
+        .expect_success()
+        .commit();
+
+    let update_by_removed_editor_request = ExecuteRequestBuilder::contract_call_by_hash(
+        metadata_editor,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_CEP78_METADATA.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_by_removed_editor_request)
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        6u16,
+        "updating metadata by a removed editor must raise InvalidTokenOwner",
+    );
+}
+
+#[test]
+fn should_record_metadata_revisions() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::CEP78)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_CEP78_METADATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_id = 0u64;
+
+    // Revisions hold the metadata as stored, not the raw argument.
+    let minted_stored_metadata = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        METADATA_CEP78,
+        &token_id.to_string(),
+    );
+
+    let update_token_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_UPDATED_CEP78_METADATA.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(update_token_metadata_request)
+        .expect_success()
+        .commit();
+
+    let revision_count = support::get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        METADATA_REVISION_COUNTS,
+        &token_id.to_string(),
+    );
+    assert_eq!(revision_count, 2u64);
+
+    let (minted_metadata, _, minter) =
+        support::get_dictionary_value_from_key::<(BTreeMap<u8, String>, u64, Key)>(
+            &builder,
+            &nft_contract_key,
+            METADATA_HISTORY,
+            &format!("{token_id}_0"),
+        );
+    assert_eq!(
+        minted_metadata,
+        BTreeMap::from([(NFTMetadataKind::CEP78 as u8, minted_stored_metadata)])
+    );
+    assert_eq!(minter, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+
+    let updated_stored_metadata = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        METADATA_CEP78,
+        &token_id.to_string(),
+    );
+    let (updated_metadata, _, updater) =
+        support::get_dictionary_value_from_key::<(BTreeMap<u8, String>, u64, Key)>(
+            &builder,
+            &nft_contract_key,
+            METADATA_HISTORY,
+            &format!("{token_id}_1"),
+        );
+    assert_eq!(
+        updated_metadata,
+        BTreeMap::from([(NFTMetadataKind::CEP78 as u8, updated_stored_metadata)])
+    );
+    assert_eq!(updater, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+
+    let metadata_at_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_METADATA_AT,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_REVISION => 1u64,
+        },
+    )
+    .build();
+
+    builder.exec(metadata_at_request).expect_success().commit();
+
+    let missing_revision_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_METADATA_AT,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_REVISION => 2u64,
+        },
+    )
+    .build();
+
+    builder.exec(missing_revision_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        192u16,
+        "requesting an unrecorded revision must raise MissingMetadataRevision",
+    );
+}
+
+#[test]
+fn should_derive_token_uri_from_base_uri() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request_without_base_uri =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(10u64)
+            .with_nft_metadata_kind(NFTMetadataKind::BaseURI)
+            .with_metadata_mutability(MetadataMutability::Immutable)
+            .build();
+
+    builder
+        .exec(install_request_without_base_uri)
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        195u16,
+        "installing the BaseURI kind without a base uri must raise MissingBaseUri",
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::BaseURI)
+        .with_metadata_mutability(MetadataMutability::Immutable)
+        .with_base_uri("https://example.com/tokens/".to_string())
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "".to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let get_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(get_metadata_request).expect_success().commit();
+
+    // The installer can move the base uri, e.g. for a reveal.
+    let set_base_uri_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_BASE_URI => "https://revealed.example.com/tokens/".to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(set_base_uri_request).expect_success().commit();
+
+    let base_uri: String =
+        support::query_stored_value(&builder, nft_contract_key, vec![BASE_URI.to_string()]);
+    assert_eq!(base_uri, "https://revealed.example.com/tokens/");
+
+    let clear_base_uri_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_BASE_URI => "".to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(clear_base_uri_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        195u16,
+        "clearing the base uri of the BaseURI kind must raise MissingBaseUri",
+    );
+}
+
+#[test]
+fn should_strictly_validate_cep78_checksum_and_token_uri() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::CEP78)
+        .with_metadata_mutability(MetadataMutability::Immutable)
+        .with_strict_metadata_validation(true)
+        .with_allowed_uri_schemes(vec!["ipfs://".to_string()])
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    let mint_with_metadata =
+        |builder: &mut InMemoryWasmTestBuilder, token_uri: &str, checksum: &str| {
+            let token_metadata = format!(
+                r#"{{"name": "John Doe", "token_uri": "{token_uri}", "checksum": "{checksum}"}}"#
+            );
+            let mint_request = ExecuteRequestBuilder::standard(
+                *DEFAULT_ACCOUNT_ADDR,
+                MINT_SESSION_WASM,
+                runtime_args! {
+                    ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                    ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                    ARG_TOKEN_META_DATA => token_metadata,
+                    ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+                },
+            )
+            .build();
+            builder.exec(mint_request);
+        };
+
+    let checksum = "940bffb3f2bba35f84313aa26da09ece3ad47045c6a1292c2bbd2df4ab1a55fb";
+
+    // https:// is allowed by default, but not by the allowlist given at installation.
+    mint_with_metadata(&mut builder, "https://www.barfoo.com", checksum);
+    builder.expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        198u16,
+        "a token uri outside of the allowlist must raise DisallowedTokenUriScheme",
+    );
+
+    mint_with_metadata(
+        &mut builder,
+        "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+        "940bffb3",
+    );
+    builder.expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        197u16,
+        "a checksum which is not a SHA-256 hex digest must raise InvalidChecksum",
+    );
+
+    mint_with_metadata(
+        &mut builder,
+        "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+        checksum,
+    );
+    builder.expect_success().commit();
+}
+
+#[test]
+fn should_reject_metadata_exceeding_max_metadata_size() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_max_metadata_size(64u64)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let oversized_metadata = "a".repeat(128);
+
+    let oversized_mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => oversized_metadata.clone(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(oversized_mint_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        203u16,
+        "minting with oversized metadata must raise MetadataTooLarge",
+    );
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "small".to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let oversized_update_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => oversized_metadata,
+        },
+    )
+    .build();
+
+    builder.exec(oversized_update_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        203u16,
+        "updating to oversized metadata must raise MetadataTooLarge",
+    );
+}
+
+#[test]
+fn should_store_compact_canonical_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::CEP78)
+        .with_metadata_storage_format(MetadataStorageFormat::Compact)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = support::get_nft_contract_hash(&builder).into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_CEP78_METADATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let actual_metadata = support::get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        METADATA_CEP78,
+        &0u64.to_string(),
+    );
+
+    // Keys are sorted and whitespace is dropped.
+    assert_eq!(
+        actual_metadata,
+        r#"{"checksum":"940bffb3f2bba35f84313aa26da09ece3ad47045c6a1292c2bbd2df4ab1a55fb","name":"John Doe","token_uri":"https://www.barfoo.com"}"#
+    );
+}
+
+#[test]
+fn should_store_binary_metadata_matching_the_declared_layout() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request_without_layout =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(10u64)
+            .with_nft_metadata_kind(NFTMetadataKind::Binary)
+            .build();
+
+    builder
+        .exec(install_request_without_layout)
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        208u16,
+        "installing the Binary kind without a layout must raise MissingBinaryMetadataLayout",
+    );
+
+    // A u8 level, a u64 experience and a String name.
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::Binary)
+        .with_binary_metadata_layout(vec![1u8, 3u8, 6u8])
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let token_metadata: Bytes = (5u8, 1200u64, "sword".to_string())
+        .to_bytes()
+        .expect("must serialize stat block")
+        .into();
+    let mut malformed_token_metadata = token_metadata.to_vec();
+    malformed_token_metadata.push(0u8);
+
+    let malformed_mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "".to_string(),
+            ARG_TOKEN_META_DATA_BYTES => Bytes::from(malformed_token_metadata),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(malformed_mint_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        210u16,
+        "binary metadata with trailing bytes must raise InvalidBinaryMetadata",
+    );
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "".to_string(),
+            ARG_TOKEN_META_DATA_BYTES => token_metadata.clone(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let actual_metadata = support::get_dictionary_value_from_key::<Bytes>(
+        &builder,
+        &nft_contract_key,
+        METADATA_BINARY,
+        &0u64.to_string(),
+    );
+
+    assert_eq!(actual_metadata, token_metadata);
+
+    // Binary metadata is recorded in the history base16 encoded.
+    let (minted_metadata, _, _) = support::get_dictionary_value_from_key::<(
+        BTreeMap<u8, String>,
+        u64,
+        Key,
+    )>(&builder, &nft_contract_key, METADATA_HISTORY, "0_0");
+    assert_eq!(
+        minted_metadata,
+        BTreeMap::from([(
+            NFTMetadataKind::Binary as u8,
+            base16::encode_lower(&token_metadata)
+        )])
+    );
+
+    let minting_contract_install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINTING_CONTRACT_WASM,
+        runtime_args! {},
+    )
+    .build();
+
+    builder
+        .exec(minting_contract_install_request)
+        .expect_success()
+        .commit();
+
+    let minting_contract_hash = get_minting_contract_hash(&builder);
+
+    let get_metadata_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        minting_contract_hash,
+        ENTRY_POINT_METADATA,
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => 0u64,
+            ARG_NFT_CONTRACT_HASH => nft_contract_key
+        },
+    )
+    .build();
+
+    builder.exec(get_metadata_request).expect_success().commit();
+
+    // The metadata entrypoint returns binary metadata base16 encoded.
+    let returned_metadata: String = support::query_stored_value(
+        &builder,
+        minting_contract_hash.into(),
+        vec!["metadata".to_string()],
+    );
+
+    assert_eq!(returned_metadata, base16::encode_lower(&token_metadata));
+
+    let get_metadata_bytes_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        minting_contract_hash,
+        ENTRY_POINT_METADATA_BYTES,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_NFT_CONTRACT_HASH => nft_contract_key
+        },
+    )
+    .build();
+
+    builder
+        .exec(get_metadata_bytes_request)
+        .expect_success()
+        .commit();
+
+    // The metadata_bytes entrypoint returns binary metadata as stored.
+    let returned_metadata_bytes: Bytes = support::query_stored_value(
+        &builder,
+        minting_contract_hash.into(),
+        vec!["metadata_bytes".to_string()],
+    );
+
+    assert_eq!(returned_metadata_bytes, token_metadata);
+}
+
+#[test]
+fn should_not_return_metadata_bytes_without_the_binary_metadata_kind() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_nft_metadata_kind(NFTMetadataKind::NFT721)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let get_metadata_bytes_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_METADATA_BYTES,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(get_metadata_bytes_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    support::assert_expected_error(
+        error,
+        47u16,
+        "metadata_bytes without the Binary metadata kind must raise MissingTokenMetaData",
+    );
+}
+
+#[test]
+fn should_populate_the_metadata_kinds_selected_for_a_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .with_optional_metadata(vec![
+            NFTMetadataKind::CEP78 as u8,
+            NFTMetadataKind::NFT721 as u8,
+        ])
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_TOKEN_METADATA_KINDS => vec![NFTMetadataKind::NFT721 as u8],
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_metadata_kinds = support::get_dictionary_value_from_key::<Option<Vec<u8>>>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_METADATA_KINDS,
+        &0u64.to_string(),
+    );
+    assert_eq!(
+        token_metadata_kinds,
+        Some(vec![
+            NFTMetadataKind::NFT721 as u8,
+            NFTMetadataKind::Raw as u8
+        ])
+    );
+
+    let get_token_metadata_kinds_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TOKEN_METADATA_KINDS,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder
+        .exec(get_token_metadata_kinds_request)
+        .expect_success()
+        .commit();
+
+    // A selected optional kind no longer fails validation silently.
+    let invalid_mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_TOKEN_METADATA_KINDS => vec![NFTMetadataKind::CEP78 as u8],
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(invalid_mint_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        88u16,
+        "a selected CEP78 kind must be valid CEP78 metadata",
+    );
+
+    let unsupported_mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_TOKEN_METADATA_KINDS => vec![NFTMetadataKind::CustomValidated as u8],
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(unsupported_mint_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        211u16,
+        "selecting a kind the collection does not support must raise UnsupportedTokenMetadataKind",
+    );
+}
+
+#[test]
+fn should_update_optional_metadata_kinds_of_tokens_minted_without_a_selection() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .with_optional_metadata(vec![NFTMetadataKind::NFT721 as u8])
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // The metadata is not valid for the optional NFT721 kind, which is then left unpopulated.
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "raw".to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    // The optional kind is still populated by a later update, as the token has no selection.
+    let update_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_METADATA,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(update_request).expect_success().commit();
+
+    let updated_metadata: String = support::get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        METADATA_NFT721,
+        &0u64.to_string(),
+    );
+    assert_eq!(updated_metadata, TEST_PRETTY_721_META_DATA);
+}
+
+#[test]
+fn should_reveal_base_uri_matching_the_provenance_hash() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let base_uri = "https://revealed.example.com/tokens/".to_string();
+    let provenance_hash = base16::encode_lower(&support::create_blake2b_hash(&base_uri));
+
+    let install_request_without_provenance_hash =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(10u64)
+            .with_nft_metadata_kind(NFTMetadataKind::BaseURI)
+            .with_metadata_mutability(MetadataMutability::Immutable)
+            .with_reveal_mode(RevealMode::Delayed)
+            .with_placeholder_uri("https://example.com/placeholder.json".to_string())
+            .build();
+
+    builder
+        .exec(install_request_without_provenance_hash)
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        215u16,
+        "a delayed reveal without a provenance hash must raise MissingProvenanceHash",
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_nft_metadata_kind(NFTMetadataKind::BaseURI)
+        .with_metadata_mutability(MetadataMutability::Immutable)
+        .with_reveal_mode(RevealMode::Delayed)
+        .with_provenance_hash(provenance_hash.clone())
+        .with_placeholder_uri("https://example.com/placeholder.json".to_string())
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => "".to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    // The committed base uri cannot be swapped through set_variables.
+    let set_base_uri_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! {
+            ARG_BASE_URI => base_uri.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(set_base_uri_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        223u16,
+        "setting the base uri of a delayed reveal must raise BaseUriLocked",
+    );
+
+    let mismatched_reveal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVEAL,
+        runtime_args! {
+            ARG_BASE_URI => "https://other.example.com/tokens/".to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mismatched_reveal_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        222u16,
+        "revealing a base uri not matching the provenance hash must raise ProvenanceHashMismatch",
+    );
+
+    let revealed: bool =
+        support::query_stored_value(&builder, nft_contract_key, vec![REVEALED.to_string()]);
+    assert!(!revealed);
+
+    let reveal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVEAL,
+        runtime_args! {
+            ARG_BASE_URI => base_uri.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(reveal_request).expect_success().commit();
+
+    let stored_base_uri: String =
+        support::query_stored_value(&builder, nft_contract_key, vec![BASE_URI.to_string()]);
+    assert_eq!(stored_base_uri, base_uri);
+
+    let revealed: bool =
+        support::query_stored_value(&builder, nft_contract_key, vec![REVEALED.to_string()]);
+    assert!(revealed);
+
+    let expected_event = Revealed::new(base_uri.clone(), provenance_hash);
+    let actual_event: Revealed = support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(actual_event, expected_event, "Expected Revealed event.");
+
+    let second_reveal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVEAL,
+        runtime_args! {
+            ARG_BASE_URI => base_uri,
+        },
+    )
+    .build();
+
+    builder.exec(second_reveal_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 221u16, "revealing twice must raise AlreadyRevealed");
+}
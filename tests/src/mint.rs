@@ -1,23 +1,28 @@
 use contract::{
     constants::{
-        APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_MINTING_MODE, ARG_OPERATOR,
-        ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE, ENTRY_POINT_MINT,
-        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_SET_APPROVALL_FOR_ALL, METADATA_CEP78,
+        APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_ISSUER, ARG_MINTING_MODE, ARG_OPERATOR,
+        ARG_PAGE_SIZE, ARG_PAGE_START, ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH,
+        ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE,
+        ENTRY_POINT_ISSUER_OF, ENTRY_POINT_MINT, ENTRY_POINT_REGISTER_OWNER,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_TOKENS_ISSUED_BY, ENTRY_POINT_TOKEN_HASH_OF,
+        ENTRY_POINT_TOKEN_INDEX_OF, ISSUED_TOKENS, ISSUED_TOKEN_COUNTS, METADATA_CEP78,
         METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW, NUMBER_OF_MINTED_TOKENS,
         PAGE_TABLE, RECEIPT_NAME, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
     },
     events::events_ces::{ApprovalForAll, Mint, RevokedForAll},
-    modalities::TokenIdentifier,
+    modalities::{TokenIdentifier, TokenInfo},
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, WasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
 use casper_execution_engine::storage::global_state::in_memory::InMemoryGlobalState;
-use casper_types::{account::AccountHash, runtime_args, CLValue, Key, RuntimeArgs};
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, CLValue, Key, RuntimeArgs,
+};
 
 use crate::utility::{
     constants::{
@@ -26,12 +31,13 @@ use crate::utility::{
         IS_APPROVED_FOR_ALL_WASM, MALFORMED_META_DATA, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
         NFT_TEST_COLLECTION, OWNER_OF_SESSION_WASM, PAGE_SIZE, TEST_COMPACT_META_DATA,
         TEST_PRETTY_721_META_DATA, TEST_PRETTY_CEP78_METADATA, TEST_PRETTY_UPDATED_CEP78_METADATA,
-        TRANSFER_SESSION_WASM,
+        TOKEN_INFO_SESSION_WASM, TRANSFER_SESSION_WASM,
     },
     installer_request_builder::{
-        InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
-        TEST_CUSTOM_METADATA, TEST_CUSTOM_METADATA_SCHEMA,
+        HashIdentifierMode, InstallerRequestBuilder, MetadataMutability, MintingMode,
+        NFTHolderMode, NFTIdentifierMode, NFTMetadataKind, OrdinalAssignmentMode,
+        OwnerReverseLookupMode, OwnershipMode, WhitelistMode, TEST_CUSTOM_METADATA,
+        TEST_CUSTOM_METADATA_SCHEMA,
     },
     support::{
         self, assert_expected_error, call_session_code_with_ret, create_funded_dummy_account,
@@ -238,6 +244,7 @@ fn should_mint() {
         token_owner,
         TokenIdentifier::Index(0),
         TEST_PRETTY_CEP78_METADATA.to_string(),
+        None,
     );
     let actual_event: Mint = support::get_event(&builder, &nft_contract_key, 0).unwrap();
     assert_eq!(actual_event, expected_event, "Expected Mint event.");
@@ -1965,3 +1972,555 @@ fn should_approve_all_with_flat_gas_cost() {
         second_set_approve_for_all_gas_cost
     )
 }
+
+#[test]
+fn should_assign_shuffled_indices_from_the_remaining_pool() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request_in_hash_mode =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(10u64)
+            .with_identifier_mode(NFTIdentifierMode::Hash)
+            .with_metadata_mutability(MetadataMutability::Immutable)
+            .with_ordinal_assignment_mode(OrdinalAssignmentMode::Shuffled)
+            .with_shuffle_seed("committed seed".to_string())
+            .build();
+
+    builder.exec(install_request_in_hash_mode).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        225u16,
+        "shuffling hash identifiers must raise InvalidOrdinalAssignmentMode",
+    );
+
+    let install_request_without_seed =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(10u64)
+            .with_ordinal_assignment_mode(OrdinalAssignmentMode::Shuffled)
+            .build();
+
+    builder.exec(install_request_without_seed).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        226u16,
+        "shuffling without a seed must raise MissingShuffleSeed",
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ordinal_assignment_mode(OrdinalAssignmentMode::Shuffled)
+        .with_shuffle_seed("committed seed".to_string())
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+    for _ in 0..10u64 {
+        let mint_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    // Every index of the supply has been assigned exactly once.
+    for token_index in 0..10u64 {
+        let token_owner = get_dictionary_value_from_key::<Key>(
+            &builder,
+            &nft_contract_key,
+            TOKEN_OWNERS,
+            &token_index.to_string(),
+        );
+        assert_eq!(token_owner, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+    }
+
+    let number_of_minted_tokens: u64 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![NUMBER_OF_MINTED_TOKENS.to_string()],
+    );
+    assert_eq!(number_of_minted_tokens, 10u64);
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        32u16,
+        "minting beyond the shuffled pool must raise TokenSupplyDepleted",
+    );
+}
+
+#[test]
+fn should_validate_custom_identifiers_against_the_installed_rules() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request_with_invalid_rules =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(10u64)
+            .with_identifier_mode(NFTIdentifierMode::Hash)
+            .with_metadata_mutability(MetadataMutability::Immutable)
+            .with_hash_identifier_mode(HashIdentifierMode::Custom)
+            .with_identifier_max_length(200u32)
+            .build();
+
+    builder
+        .exec(install_request_with_invalid_rules)
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        232u16,
+        "identifiers longer than the dictionary keys allow must raise InvalidIdentifierRules",
+    );
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_identifier_mode(NFTIdentifierMode::Hash)
+        .with_metadata_mutability(MetadataMutability::Immutable)
+        .with_hash_identifier_mode(HashIdentifierMode::Custom)
+        .with_identifier_charset("0123456789".to_string())
+        .with_identifier_min_length(8u32)
+        .with_identifier_max_length(12u32)
+        .with_identifier_prefix("SKU-".to_string())
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_key: Key = get_nft_contract_hash(&builder).into();
+
+    let mint_request_without_identifier = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder
+        .exec(mint_request_without_identifier)
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        27u16,
+        "custom identifiers cannot be derived from the metadata and must raise MissingTokenID",
+    );
+
+    for invalid_identifier in ["0001234", "SKU-12", "SKU-123456789", "SKU-12AB"] {
+        let mint_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+                ARG_TOKEN_HASH => invalid_identifier.to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_failure();
+        let error = builder.get_error().expect("must have error");
+        assert_expected_error(
+            error,
+            233u16,
+            "identifiers breaking the installed rules must raise InvalidCustomIdentifier",
+        );
+    }
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_TOKEN_HASH => "SKU-0042".to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_owner =
+        get_dictionary_value_from_key::<Key>(&builder, &nft_contract_key, TOKEN_OWNERS, "SKU-0042");
+    assert_eq!(token_owner, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+}
+
+#[test]
+fn should_accept_either_token_identifier_form() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_identifier_mode(NFTIdentifierMode::Hash)
+        .with_metadata_mutability(MetadataMutability::Immutable)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let token_hash = base16::encode_lower(&support::create_blake2b_hash(TEST_PRETTY_721_META_DATA));
+
+    // The ordinal index resolves to the hash identifier of a contract in hash mode.
+    let spender = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SPENDER => spender
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let approved = get_dictionary_value_from_key::<Option<Key>>(
+        &builder,
+        &nft_contract_key,
+        APPROVED,
+        &token_hash,
+    );
+    assert_eq!(approved, Some(spender));
+
+    let token_index_of_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TOKEN_INDEX_OF,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash.clone(),
+        },
+    )
+    .build();
+    builder
+        .exec(token_index_of_request)
+        .expect_success()
+        .commit();
+
+    let token_hash_of_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TOKEN_HASH_OF,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+    builder
+        .exec(token_hash_of_request)
+        .expect_success()
+        .commit();
+
+    let unknown_index_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TOKEN_HASH_OF,
+        runtime_args! {
+            ARG_TOKEN_ID => 5u64,
+        },
+    )
+    .build();
+    builder.exec(unknown_index_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        28u16,
+        "an index without a minted token must raise InvalidTokenIdentifier",
+    );
+}
+
+#[test]
+fn should_only_identify_ordinal_tokens_by_index() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    // Ordinal tokens may share their metadata, so its digest does not identify them.
+    let token_hash = base16::encode_lower(&support::create_blake2b_hash(TEST_PRETTY_721_META_DATA));
+    let spender = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+
+    let approve_by_hash_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_HASH => token_hash,
+            ARG_SPENDER => spender
+        },
+    )
+    .build();
+    builder.exec(approve_by_hash_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        27u16,
+        "an ordinal token passed by hash must raise MissingTokenID",
+    );
+
+    let token_hash_of_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TOKEN_HASH_OF,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+    builder.exec(token_hash_of_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        99u16,
+        "looking up the hash of an ordinal token must raise InvalidIdentifierMode",
+    );
+}
+
+#[test]
+fn should_return_the_token_info_of_a_minted_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+        },
+    )
+    .build();
+
+    builder.exec(mint_request).expect_success().commit();
+
+    let spender = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_SPENDER => spender
+        },
+    )
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let token_info: TokenInfo = call_session_code_with_ret(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => 0u64,
+        },
+        TOKEN_INFO_SESSION_WASM,
+        ARG_KEY_NAME,
+    );
+
+    let stored_metadata =
+        get_dictionary_value_from_key::<String>(&builder, &nft_contract_key, METADATA_NFT721, "0");
+    let mut expected_metadata = BTreeMap::new();
+    expected_metadata.insert(NFTMetadataKind::NFT721 as u8, stored_metadata);
+
+    let expected_token_info = TokenInfo {
+        token_index: 0u64,
+        // Ordinal tokens have no hash.
+        token_hash: None,
+        owner: Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        issuer: Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        approved: Some(spender),
+        burnt: false,
+        metadata: expected_metadata,
+    };
+    assert_eq!(token_info, expected_token_info);
+}
+
+#[test]
+fn should_index_the_tokens_issued_by_each_minter() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let issuer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    for _ in 0..3 {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => Key::Account(AccountHash::new(ACCOUNT_USER_1)),
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    let issued_token_count = get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        ISSUED_TOKEN_COUNTS,
+        &DEFAULT_ACCOUNT_ADDR.to_string(),
+    );
+    assert_eq!(issued_token_count, 3u64);
+
+    let mut position_bytes = issuer.to_bytes().unwrap();
+    position_bytes.append(&mut 1u64.to_bytes().unwrap());
+    let issued_token = get_dictionary_value_from_key::<String>(
+        &builder,
+        &nft_contract_key,
+        ISSUED_TOKENS,
+        &base16::encode_lower(&support::create_blake2b_hash(position_bytes)),
+    );
+    assert_eq!(issued_token, "1");
+
+    let issuer_of_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_ISSUER_OF,
+        runtime_args! {
+            ARG_TOKEN_ID => 2u64,
+        },
+    )
+    .build();
+    builder.exec(issuer_of_request).expect_success().commit();
+
+    let tokens_issued_by_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TOKENS_ISSUED_BY,
+        runtime_args! {
+            ARG_ISSUER => issuer,
+            ARG_PAGE_START => 1u64,
+            ARG_PAGE_SIZE => 5u64,
+        },
+    )
+    .build();
+    builder
+        .exec(tokens_issued_by_request)
+        .expect_success()
+        .commit();
+
+    let empty_page_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TOKENS_ISSUED_BY,
+        runtime_args! {
+            ARG_ISSUER => issuer,
+            ARG_PAGE_START => 0u64,
+            ARG_PAGE_SIZE => 0u64,
+        },
+    )
+    .build();
+    builder.exec(empty_page_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        241u16,
+        "an empty page must raise InvalidIssuedTokensPage",
+    );
+}
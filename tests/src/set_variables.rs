@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
@@ -6,11 +8,11 @@ use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
 use contract::{
     constants::{
         ACL_PACKAGE_MODE, ALLOW_MINTING, ARG_ACL_PACKAGE_MODE, ARG_ALLOW_MINTING,
-        ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_OPERATOR_MODE, ENTRY_POINT_SET_VARIABLES,
-        OPERATOR_BURN_MODE, PACKAGE_OPERATOR_MODE,
+        ARG_COLLECTION_EXTERNAL_LINK, ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_OPERATOR_MODE,
+        COLLECTION_METADATA, ENTRY_POINT_SET_VARIABLES, OPERATOR_BURN_MODE, PACKAGE_OPERATOR_MODE,
     },
     error::NFTCoreError,
-    events::events_ces::VariablesSet,
+    events::events_ces::{CollectionMetadataUpdated, VariablesSet},
 };
 
 use crate::utility::{
@@ -284,3 +286,81 @@ fn installer_should_be_able_to_toggle_operator_burn_mode() {
     let actual_event: VariablesSet = support::get_event(&builder, &nft_contract_key, 0).unwrap();
     assert_eq!(actual_event, expected_event, "Expected VariablesSet event.");
 }
+
+#[test]
+fn installer_should_be_able_to_update_collection_metadata() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_collection_description("A collection of test NFTs".to_string())
+        .with_collection_image("https://example.com/banner.png".to_string())
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let collection_metadata: BTreeMap<String, String> = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![COLLECTION_METADATA.to_string()],
+    );
+
+    assert_eq!(
+        collection_metadata["description"],
+        "A collection of test NFTs"
+    );
+    assert_eq!(
+        collection_metadata["image"],
+        "https://example.com/banner.png"
+    );
+    assert_eq!(collection_metadata["contract_uri"], "");
+    assert_eq!(collection_metadata["external_link"], "");
+
+    let installer_set_variables_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_VARIABLES,
+        runtime_args! { ARG_COLLECTION_EXTERNAL_LINK => "https://example.com".to_string() },
+    )
+    .build();
+
+    builder
+        .exec(installer_set_variables_request)
+        .expect_success()
+        .commit();
+
+    let collection_metadata: BTreeMap<String, String> = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![COLLECTION_METADATA.to_string()],
+    );
+
+    assert_eq!(collection_metadata["external_link"], "https://example.com");
+    assert_eq!(
+        collection_metadata["description"],
+        "A collection of test NFTs"
+    );
+
+    // Expect CollectionMetadataUpdated event.
+    let expected_event = CollectionMetadataUpdated::new(
+        "".to_string(),
+        "A collection of test NFTs".to_string(),
+        "https://example.com/banner.png".to_string(),
+        "https://example.com".to_string(),
+    );
+    let actual_event: CollectionMetadataUpdated =
+        support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected CollectionMetadataUpdated event."
+    );
+}
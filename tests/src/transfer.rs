@@ -4,17 +4,21 @@ use casper_engine_test_support::{
 };
 use casper_execution_engine::storage::global_state::in_memory::InMemoryGlobalState;
 use casper_types::{
-    account::AccountHash, runtime_args, Key, PublicKey, RuntimeArgs, SecretKey, U512,
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    crypto, runtime_args, ContractPackageHash, Key, PublicKey, RuntimeArgs, SecretKey, U512,
 };
 use contract::{
     constants::{
-        ACL_WHITELIST, APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_OPERATOR,
-        ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE, ENTRY_POINT_MINT,
-        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
-        ENTRY_POINT_TRANSFER, PAGE_TABLE, TOKEN_COUNT, TOKEN_OWNERS,
+        ACL_WHITELIST, APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_DEADLINE, ARG_MEMO,
+        ARG_NONCE, ARG_OPERATOR, ARG_PUBLIC_KEY, ARG_SIGNATURE, ARG_SOURCE_KEY, ARG_SPENDER,
+        ARG_TARGET_KEY, ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER,
+        BURNT_TOKENS, ENTRY_POINT_APPROVE, ENTRY_POINT_FORCE_BURN, ENTRY_POINT_FORCE_TRANSFER,
+        ENTRY_POINT_MINT, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_TRANSFER, ENTRY_POINT_TRANSFER_BY_SIGNATURE,
+        PAGE_TABLE, PREFIX_HASH_KEY_NAME, TOKEN_COUNT, TOKEN_OWNERS,
     },
-    events::events_ces::{Approval, ApprovalRevoked, Transfer},
+    events::events_ces::{Approval, ApprovalRevoked, ForcedBurn, ForcedTransfer, Transfer},
     modalities::{TokenIdentifier, TransferFilterContractResult},
 };
 
@@ -27,8 +31,8 @@ use crate::utility::{
         TRANSFER_SESSION_WASM,
     },
     installer_request_builder::{
-        InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
+        ClawbackMode, InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode,
+        NFTIdentifierMode, NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
     },
     support::{
         self, assert_expected_error, create_funded_dummy_account, get_dictionary_value_from_key,
@@ -241,6 +245,7 @@ fn should_transfer_token_from_sender_to_receiver() {
         None,
         token_receiver_key,
         TokenIdentifier::Index(token_id),
+        None,
     );
     let actual_event: Transfer = support::get_event(&builder, &nft_contract_key, 1).unwrap();
     assert_eq!(actual_event, expected_event, "Expected Transfer event.");
@@ -2886,3 +2891,712 @@ fn should_allow_package_operator_to_revoke_with_package_operator_mode() {
 
     assert_eq!(actual_token_owner, *DEFAULT_ACCOUNT_ADDR);
 }
+
+#[test]
+fn should_transfer_token_by_signature_through_relayer() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let nft_contract_package_hash = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(&format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"))
+        .expect("must have package hash entry in named keys")
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .expect("must get package hash");
+
+    // The token owner signs the authorization off-chain; the relayer submits it.
+    let owner_secret_key = SecretKey::ed25519_from_bytes(ACCOUNT_USER_1).unwrap();
+    let owner_public_key = PublicKey::from(&owner_secret_key);
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_owner_key = Key::Account(token_owner);
+    assert_eq!(token_owner, owner_public_key.to_account_hash());
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    let relayer_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let token_receiver = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let token_receiver_key = Key::Account(token_receiver);
+
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_receiver_key
+        },
+    )
+    .build();
+
+    builder.exec(register_request).expect_success().commit();
+
+    let token_id = 0u64;
+    let nonce = 0u64;
+    let deadline = u64::MAX;
+
+    let digest = support::create_blake2b_hash(
+        (
+            nft_contract_package_hash,
+            TokenIdentifier::Index(token_id),
+            token_receiver_key,
+            nonce,
+            deadline,
+        )
+            .to_bytes()
+            .unwrap(),
+    );
+    let signature = crypto::sign(digest, &owner_secret_key, &owner_public_key);
+
+    let transfer_by_signature_args = runtime_args! {
+        ARG_TOKEN_ID => token_id,
+        ARG_TARGET_KEY => token_receiver_key,
+        ARG_NONCE => nonce,
+        ARG_DEADLINE => deadline,
+        ARG_PUBLIC_KEY => owner_public_key,
+        ARG_SIGNATURE => Bytes::from(signature.to_bytes().unwrap()),
+    };
+
+    let transfer_by_signature_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_BY_SIGNATURE,
+        transfer_by_signature_args.clone(),
+    )
+    .build();
+
+    builder
+        .exec(transfer_by_signature_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_owner: Key = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_id.to_string(),
+    );
+
+    assert_eq!(actual_token_owner, token_receiver_key);
+
+    // Expect Transfer event with the relayer as spender.
+    let expected_event = Transfer::new(
+        token_owner_key,
+        Some(relayer_key),
+        token_receiver_key,
+        TokenIdentifier::Index(token_id),
+        None,
+    );
+    let actual_event: Transfer = support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(actual_event, expected_event, "Expected Transfer event.");
+
+    // Replaying the same authorization must fail as the nonce has been consumed.
+    let replay_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER_BY_SIGNATURE,
+        transfer_by_signature_args,
+    )
+    .build();
+
+    builder.exec(replay_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        176u16,
+        "replayed transfer authorization must raise InvalidNonce",
+    );
+}
+
+#[test]
+fn should_reject_invalid_transfer_authorizations() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let nft_contract_package_hash = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(&format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"))
+        .expect("must have package hash entry in named keys")
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .expect("must get package hash");
+
+    let owner_secret_key = SecretKey::ed25519_from_bytes(ACCOUNT_USER_1).unwrap();
+    let owner_public_key = PublicKey::from(&owner_secret_key);
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let other_secret_key = SecretKey::ed25519_from_bytes(ACCOUNT_USER_3).unwrap();
+    let other_public_key = PublicKey::from(&other_secret_key);
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(token_owner),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    let token_id = 0u64;
+    let token_receiver_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_2),
+    ));
+
+    let sign = |target_key: Key, nonce: u64, deadline: u64, secret_key: &SecretKey| {
+        let public_key = PublicKey::from(secret_key);
+        let digest = support::create_blake2b_hash(
+            (
+                nft_contract_package_hash,
+                TokenIdentifier::Index(token_id),
+                target_key,
+                nonce,
+                deadline,
+            )
+                .to_bytes()
+                .unwrap(),
+        );
+        let signature = crypto::sign(digest, secret_key, &public_key);
+        Bytes::from(signature.to_bytes().unwrap())
+    };
+
+    let cases = vec![
+        (
+            // The authorization expired before the deploy was executed.
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_TARGET_KEY => token_receiver_key,
+                ARG_NONCE => 0u64,
+                ARG_DEADLINE => 1_000u64,
+                ARG_PUBLIC_KEY => owner_public_key.clone(),
+                ARG_SIGNATURE => sign(token_receiver_key, 0u64, 1_000u64, &owner_secret_key),
+            },
+            179u16,
+            "expired authorization must raise ExpiredTransferAuthorization",
+        ),
+        (
+            // The authorization is signed by an account which does not own the token.
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_TARGET_KEY => token_receiver_key,
+                ARG_NONCE => 0u64,
+                ARG_DEADLINE => u64::MAX,
+                ARG_PUBLIC_KEY => other_public_key,
+                ARG_SIGNATURE => sign(token_receiver_key, 0u64, u64::MAX, &other_secret_key),
+            },
+            6u16,
+            "authorization by a non owner must raise InvalidTokenOwner",
+        ),
+        (
+            // The target key differs from the one the owner signed.
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_TARGET_KEY => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_NONCE => 0u64,
+                ARG_DEADLINE => u64::MAX,
+                ARG_PUBLIC_KEY => owner_public_key.clone(),
+                ARG_SIGNATURE => sign(token_receiver_key, 0u64, u64::MAX, &owner_secret_key),
+            },
+            172u16,
+            "tampered target key must raise InvalidSignature",
+        ),
+        (
+            // The signature bytes do not deserialize into a signature.
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_TARGET_KEY => token_receiver_key,
+                ARG_NONCE => 0u64,
+                ARG_DEADLINE => u64::MAX,
+                ARG_PUBLIC_KEY => owner_public_key.clone(),
+                ARG_SIGNATURE => Bytes::from(vec![0xffu8; 8]),
+            },
+            172u16,
+            "tampered signature must raise InvalidSignature",
+        ),
+        (
+            // The nonce does not match the current nonce of the owner.
+            runtime_args! {
+                ARG_TOKEN_ID => token_id,
+                ARG_TARGET_KEY => token_receiver_key,
+                ARG_NONCE => 1u64,
+                ARG_DEADLINE => u64::MAX,
+                ARG_PUBLIC_KEY => owner_public_key.clone(),
+                ARG_SIGNATURE => sign(token_receiver_key, 1u64, u64::MAX, &owner_secret_key),
+            },
+            176u16,
+            "wrong nonce must raise InvalidNonce",
+        ),
+    ];
+
+    for (transfer_by_signature_args, expected_error, message) in cases {
+        let transfer_by_signature_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_TRANSFER_BY_SIGNATURE,
+            transfer_by_signature_args,
+        )
+        .with_block_time(2_000u64)
+        .build();
+
+        builder.exec(transfer_by_signature_request).expect_failure();
+
+        let error = builder.get_error().expect("must have error");
+        assert_expected_error(error, expected_error, message);
+    }
+
+    let actual_token_owner: Key = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(actual_token_owner, Key::Account(token_owner));
+}
+
+#[test]
+fn should_allow_installer_to_force_transfer_and_force_burn_with_clawback_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_clawback_mode(ClawbackMode::Enabled)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_owner_key = Key::Account(token_owner);
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    let token_receiver = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_2));
+    let token_receiver_key = Key::Account(token_receiver);
+
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_receiver_key
+        },
+    )
+    .build();
+
+    builder.exec(register_request).expect_success().commit();
+
+    let token_id = 0u64;
+    let force_transfer_args = runtime_args! {
+        ARG_TOKEN_ID => token_id,
+        ARG_TARGET_KEY => token_receiver_key,
+    };
+
+    // Only the installer can seize tokens.
+    let non_installer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        token_receiver,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_TRANSFER,
+        force_transfer_args.clone(),
+    )
+    .build();
+
+    builder.exec(non_installer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 1u16, "non installer must raise InvalidAccount");
+
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_TRANSFER,
+        force_transfer_args,
+    )
+    .build();
+
+    builder
+        .exec(force_transfer_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_owner: Key = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_id.to_string(),
+    );
+    assert_eq!(actual_token_owner, token_receiver_key);
+
+    let installer_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let expected_event = ForcedTransfer::new(
+        token_owner_key,
+        installer_key,
+        token_receiver_key,
+        TokenIdentifier::Index(token_id),
+    );
+    let actual_event: ForcedTransfer = support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(
+        actual_event, expected_event,
+        "Expected ForcedTransfer event."
+    );
+
+    let force_burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+        },
+    )
+    .build();
+
+    builder.exec(force_burn_request).expect_success().commit();
+
+    // This will error if token is not registered as burnt.
+    get_dictionary_value_from_key::<()>(
+        &builder,
+        &nft_contract_key,
+        BURNT_TOKENS,
+        &token_id.to_string(),
+    );
+
+    let actual_balance: u64 = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &token_receiver.to_string(),
+    );
+    assert_eq!(actual_balance, 0u64);
+
+    let expected_event = ForcedBurn::new(
+        token_receiver_key,
+        TokenIdentifier::Index(token_id),
+        installer_key,
+    );
+    let actual_event: ForcedBurn = support::get_event(&builder, &nft_contract_key, 2).unwrap();
+    assert_eq!(actual_event, expected_event, "Expected ForcedBurn event.");
+}
+
+#[test]
+fn should_allow_forced_transfer_in_assigned_but_not_minter_ownership_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Assigned)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_clawback_mode(ClawbackMode::Enabled)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(token_owner),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    // The installer can reassign a token its owner cannot transfer.
+    let token_receiver_key = Key::Account(AccountHash::new(ACCOUNT_USER_2));
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder
+        .exec(force_transfer_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_owner: Key =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_OWNERS, &0u64.to_string());
+    assert_eq!(actual_token_owner, token_receiver_key);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Minter)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_clawback_mode(ClawbackMode::Enabled)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    // Tokens of the Minter ownership mode always belong to their minter.
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TARGET_KEY => token_receiver_key,
+        },
+    )
+    .build();
+
+    builder.exec(force_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        63u16,
+        "forced transfers must raise InvalidOwnershipMode in the Minter ownership mode",
+    );
+}
+
+#[test]
+fn should_disallow_forced_transfer_without_clawback_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let token_owner = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(token_owner),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    let force_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_FORCE_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+            ARG_TARGET_KEY => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        },
+    )
+    .build();
+
+    builder.exec(force_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        245u16,
+        "forced transfers must raise InvalidClawbackMode when not opted in",
+    );
+}
+
+#[test]
+fn should_include_memo_in_transfer_event() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(1u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => token_owner_key,
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    let token_receiver = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_receiver_key = Key::Account(token_receiver);
+    let token_id = 0u64;
+
+    // Memos longer than the cap are rejected.
+    let long_memo_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+            ARG_MEMO => "x".repeat(257),
+        },
+    )
+    .build();
+
+    builder.exec(long_memo_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 246u16, "long memo must raise InvalidMemo");
+
+    let memo = "order-1042".to_string();
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+            ARG_MEMO => memo.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    // Expect Transfer event carrying the memo.
+    let expected_event = Transfer::new(
+        token_owner_key,
+        None,
+        token_receiver_key,
+        TokenIdentifier::Index(token_id),
+        Some(memo),
+    );
+    let actual_event: Transfer = support::get_event(&builder, &nft_contract_key, 1).unwrap();
+    assert_eq!(actual_event, expected_event, "Expected Transfer event.");
+}
@@ -3,29 +3,36 @@ use casper_engine_test_support::{
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
 
+use casper_event_standard::Schemas;
 use casper_types::{account::AccountHash, runtime_args, CLValue, ContractHash, Key, RuntimeArgs};
 use contract::{
     constants::{
         ACCESS_KEY_NAME_1_0_0, ACL_PACKAGE_MODE, ARG_ACCESS_KEY_NAME_1_0_0, ARG_ACL_PACKAGE_MODE,
         ARG_COLLECTION_NAME, ARG_EVENTS_MODE, ARG_HASH_KEY_NAME_1_0_0, ARG_NAMED_KEY_CONVENTION,
         ARG_OPERATOR_BURN_MODE, ARG_PACKAGE_OPERATOR_MODE, ARG_SOURCE_KEY, ARG_TARGET_KEY,
-        ARG_TOKEN_HASH, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY,
-        ENTRY_POINT_MINT, ENTRY_POINT_REGISTER_OWNER, NUMBER_OF_MINTED_TOKENS, OPERATOR_BURN_MODE,
-        PACKAGE_OPERATOR_MODE, PAGE_LIMIT, PREFIX_ACCESS_KEY_NAME, PREFIX_HASH_KEY_NAME,
-        RECEIPT_NAME, UNMATCHED_HASH_COUNT,
+        ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ARG_TOTAL_TOKEN_SUPPLY,
+        ENTRY_POINT_BURN, ENTRY_POINT_CIRCULATING_SUPPLY, ENTRY_POINT_MINT,
+        ENTRY_POINT_NUMBER_OF_BURNT_TOKENS, ENTRY_POINT_NUMBER_OF_HOLDERS,
+        ENTRY_POINT_NUMBER_OF_MINTED_TOKENS, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_TRANSFER,
+        NUMBER_OF_MINTED_TOKENS, OPERATOR_BURN_MODE, PACKAGE_OPERATOR_MODE, PAGE_LIMIT,
+        PREFIX_ACCESS_KEY_NAME, PREFIX_HASH_KEY_NAME, RECEIPT_NAME, UNMATCHED_HASH_COUNT,
+    },
+    events::events_ces::{
+        Approval, ApprovalForAll, ApprovalRevoked, Burn, CollectionMetadataUpdated, ForcedBurn,
+        ForcedTransfer, MetadataFrozen, MetadataUpdated, Migration, Mint, Revealed, Transfer,
+        VariablesSet,
     },
-    events::events_ces::Migration,
     modalities::EventsMode,
 };
 
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH,
+        ACCOUNT_USER_1, ARG_ENTRY_POINT, ARG_IS_HASH_IDENTIFIER_MODE, ARG_NFT_CONTRACT_HASH,
         ARG_NFT_CONTRACT_PACKAGE_HASH, CONTRACT_1_0_0_WASM, CONTRACT_1_1_0_WASM,
         CONTRACT_1_2_0_WASM, CONTRACT_1_3_0_WASM, CONTRACT_1_4_0_WASM, CONTRACT_1_5_0_WASM,
         MANGLE_NAMED_KEYS, MINT_1_0_0_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
-        NFT_TEST_COLLECTION, NFT_TEST_SYMBOL, PAGE_SIZE, TRANSFER_SESSION_WASM,
-        UPDATED_RECEIPTS_WASM,
+        NFT_TEST_COLLECTION, NFT_TEST_SYMBOL, PAGE_SIZE, SUPPLY_STATISTICS_SESSION_WASM,
+        TRANSFER_SESSION_WASM, UPDATED_RECEIPTS_WASM,
     },
     installer_request_builder::{
         InstallerRequestBuilder, MetadataMutability, NFTIdentifierMode, NFTMetadataKind,
@@ -38,6 +45,24 @@ const OWNED_TOKENS: &str = "owned_tokens";
 const MANGLED_ACCESS_KEY_NAME: &str = "mangled_access_key";
 const MANGLED_HASH_KEY_NAME: &str = "mangled_hash_key";
 
+fn current_events_schemas() -> Schemas {
+    Schemas::new()
+        .with::<Mint>()
+        .with::<Burn>()
+        .with::<ForcedBurn>()
+        .with::<Approval>()
+        .with::<ApprovalRevoked>()
+        .with::<ApprovalForAll>()
+        .with::<Transfer>()
+        .with::<ForcedTransfer>()
+        .with::<MetadataUpdated>()
+        .with::<MetadataFrozen>()
+        .with::<VariablesSet>()
+        .with::<CollectionMetadataUpdated>()
+        .with::<Revealed>()
+        .with::<Migration>()
+}
+
 #[test]
 fn should_safely_upgrade_in_ordinal_identifier_mode() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -1478,6 +1503,18 @@ fn should_safely_upgrade_from_1_0_0_to_1_5_0_to_current_version() {
     let expected_event = Migration::new();
     let actual_event: Migration = support::get_event(&builder, &nft_contract_key, 0).unwrap();
     assert_eq!(actual_event, expected_event, "Expected Migration event.");
+
+    // Expect the schemas of the current events to replace those registered by 1.5.0.
+    let actual_schemas: Schemas = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![casper_event_standard::EVENTS_SCHEMA.to_string()],
+    );
+    assert_eq!(
+        actual_schemas,
+        current_events_schemas(),
+        "Schemas mismatch."
+    );
 }
 
 #[test]
@@ -1531,6 +1568,13 @@ fn should_safely_upgrade_from_1_5_0_to_current_version_without_supplying_events_
         builder.exec(mint_request).expect_success().commit();
     }
 
+    let schemas_pre_migration: Schemas = support::query_stored_value(
+        &builder,
+        nft_contract_key_1_5_0,
+        vec![casper_event_standard::EVENTS_SCHEMA.to_string()],
+    );
+    assert_ne!(schemas_pre_migration, current_events_schemas());
+
     let upgrade_request = ExecuteRequestBuilder::standard(
         *DEFAULT_ACCOUNT_ADDR,
         NFT_CONTRACT_WASM,
@@ -1565,6 +1609,18 @@ fn should_safely_upgrade_from_1_5_0_to_current_version_without_supplying_events_
     let actual_event: Migration =
         support::get_event(&builder, &nft_contract_key, expected_event_index).unwrap();
     assert_eq!(actual_event, expected_event, "Expected Migration event.");
+
+    // Expect the schemas of the current events to be registered.
+    let actual_schemas: Schemas = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![casper_event_standard::EVENTS_SCHEMA.to_string()],
+    );
+    assert_eq!(
+        actual_schemas,
+        current_events_schemas(),
+        "Schemas mismatch."
+    );
 }
 
 #[test]
@@ -1772,3 +1828,143 @@ fn should_safely_upgrade_from_1_0_0_to_current_version() {
     let actual_event: Migration = support::get_event(&builder, &nft_contract_key, 0).unwrap();
     assert_eq!(actual_event, expected_event, "Expected Migration event.");
 }
+
+fn assert_supply_statistics(
+    builder: &mut InMemoryWasmTestBuilder,
+    nft_contract_key: Key,
+    expected_statistics: [(&str, u64); 4],
+) {
+    for (entry_point, expected_statistic) in expected_statistics {
+        let statistic = support::call_session_code_with_ret::<u64>(
+            builder,
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_key,
+            runtime_args! {
+                ARG_ENTRY_POINT => entry_point.to_string(),
+            },
+            SUPPLY_STATISTICS_SESSION_WASM,
+            entry_point,
+        );
+        assert_eq!(statistic, expected_statistic, "unexpected {entry_point}");
+    }
+}
+
+#[test]
+fn should_count_supply_statistics_of_tokens_minted_before_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, CONTRACT_1_5_0_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::NoLookUp)
+        .with_metadata_mutability(MetadataMutability::Mutable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash_1_5_0: ContractHash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key_1_5_0: Key = nft_contract_hash_1_5_0.into();
+
+    // Build of prestate before migration, with one of three tokens burnt.
+    for _i in 0..3 {
+        let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash_1_5_0,
+            ENTRY_POINT_MINT,
+            runtime_args! {
+                ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+                ARG_TOKEN_META_DATA => "",
+            },
+        )
+        .build();
+
+        builder.exec(mint_request).expect_success().commit();
+    }
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash_1_5_0,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key_1_5_0,
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Custom as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => format!("{PREFIX_ACCESS_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_HASH_KEY_NAME_1_0_0 => format!("{PREFIX_HASH_KEY_NAME}_{NFT_TEST_COLLECTION}"),
+            ARG_TOTAL_TOKEN_SUPPLY => 10u64,
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_success().commit();
+
+    let nft_contract_hash = support::get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    assert_supply_statistics(
+        &mut builder,
+        nft_contract_key,
+        [
+            (ENTRY_POINT_NUMBER_OF_MINTED_TOKENS, 3u64),
+            (ENTRY_POINT_NUMBER_OF_BURNT_TOKENS, 1u64),
+            (ENTRY_POINT_CIRCULATING_SUPPLY, 2u64),
+            (ENTRY_POINT_NUMBER_OF_HOLDERS, 1u64),
+        ],
+    );
+
+    // The statistics keep track of the activity after the upgrade.
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_SOURCE_KEY => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TARGET_KEY => Key::Account(AccountHash::new(ACCOUNT_USER_1)),
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 2u64,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    assert_supply_statistics(
+        &mut builder,
+        nft_contract_key,
+        [
+            (ENTRY_POINT_NUMBER_OF_MINTED_TOKENS, 3u64),
+            (ENTRY_POINT_NUMBER_OF_BURNT_TOKENS, 2u64),
+            (ENTRY_POINT_CIRCULATING_SUPPLY, 1u64),
+            (ENTRY_POINT_NUMBER_OF_HOLDERS, 1u64),
+        ],
+    );
+}
@@ -14,9 +14,12 @@ pub const MINTING_CONTRACT_WASM: &str = "minting_contract.wasm";
 pub const TRANSFER_FILTER_CONTRACT_WASM: &str = "transfer_filter_contract.wasm";
 pub const NFT_CONTRACT_WASM: &str = "contract.wasm";
 pub const OWNER_OF_SESSION_WASM: &str = "owner_of_call.wasm";
+pub const SUPPLY_STATISTICS_SESSION_WASM: &str = "supply_statistics_call.wasm";
+pub const TOKEN_INFO_SESSION_WASM: &str = "token_info_call.wasm";
 pub const TRANSFER_SESSION_WASM: &str = "transfer_call.wasm";
 pub const UPDATED_RECEIPTS_WASM: &str = "updated_receipts.wasm";
 
+pub const ARG_ENTRY_POINT: &str = "entry_point";
 pub const ARG_IS_HASH_IDENTIFIER_MODE: &str = "is_hash_identifier_mode";
 pub const ARG_KEY_NAME: &str = "key_name";
 pub const ARG_NFT_CONTRACT_HASH: &str = "nft_contract_hash";
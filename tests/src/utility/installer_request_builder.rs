@@ -4,12 +4,19 @@ use casper_types::{
     account::AccountHash, bytesrepr::Bytes, CLValue, ContractHash, Key, RuntimeArgs,
 };
 use contract::constants::{
-    ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST, ARG_ADDITIONAL_REQUIRED_METADATA, ARG_ALLOW_MINTING,
-    ARG_BURN_MODE, ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST,
-    ARG_EVENTS_MODE, ARG_HOLDER_MODE, ARG_IDENTIFIER_MODE, ARG_JSON_SCHEMA,
-    ARG_METADATA_MUTABILITY, ARG_MINTING_MODE, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND,
-    ARG_NFT_METADATA_KIND, ARG_OPERATOR_BURN_MODE, ARG_OPTIONAL_METADATA, ARG_OWNERSHIP_MODE,
-    ARG_OWNER_LOOKUP_MODE, ARG_PACKAGE_OPERATOR_MODE, ARG_TOTAL_TOKEN_SUPPLY,
+    ARG_ACL_PACKAGE_MODE, ARG_ACL_WHITELIST, ARG_ADDITIONAL_REQUIRED_METADATA,
+    ARG_ALLOWED_URI_SCHEMES, ARG_ALLOW_MINTING, ARG_BASE_URI, ARG_BINARY_METADATA_LAYOUT,
+    ARG_BURN_MODE, ARG_BURN_STORAGE_MODE, ARG_CLAWBACK_MODE, ARG_COLLECTION_DESCRIPTION,
+    ARG_COLLECTION_IMAGE, ARG_COLLECTION_NAME, ARG_COLLECTION_SYMBOL, ARG_CONTRACT_WHITELIST,
+    ARG_EVENTS_MODE, ARG_HASH_IDENTIFIER_MODE, ARG_HOLDER_MODE, ARG_IDENTIFIER_CHARSET,
+    ARG_IDENTIFIER_MAX_LENGTH, ARG_IDENTIFIER_MIN_LENGTH, ARG_IDENTIFIER_MODE,
+    ARG_IDENTIFIER_PREFIX, ARG_JSON_SCHEMA, ARG_LOCKED_METADATA_FIELDS, ARG_MAX_METADATA_SIZE,
+    ARG_METADATA_EDITOR, ARG_METADATA_MUTABILITY, ARG_METADATA_STORAGE_FORMAT,
+    ARG_METADATA_UPDATE_MODE, ARG_MINTING_MODE, ARG_NAMED_KEY_CONVENTION, ARG_NFT_KIND,
+    ARG_NFT_METADATA_KIND, ARG_OPERATOR_BURN_MODE, ARG_OPTIONAL_METADATA,
+    ARG_ORDINAL_ASSIGNMENT_MODE, ARG_OWNERSHIP_MODE, ARG_OWNER_LOOKUP_MODE,
+    ARG_PACKAGE_OPERATOR_MODE, ARG_PLACEHOLDER_URI, ARG_PROVENANCE_HASH, ARG_REVEAL_MODE,
+    ARG_SHUFFLE_SEED, ARG_STRICT_METADATA_VALIDATION, ARG_TOTAL_TOKEN_SUPPLY,
     ARG_TRANSFER_FILTER_CONTRACT, ARG_WHITELIST_MODE,
 };
 use once_cell::sync::Lazy;
@@ -18,7 +25,9 @@ use std::collections::BTreeMap;
 
 // Modalities reexports.
 pub use contract::modalities::{
-    EventsMode, MintingMode, NFTHolderMode, NFTKind, OwnershipMode, TokenIdentifier, WhitelistMode,
+    BurnStorageMode, ClawbackMode, EventsMode, HashIdentifierMode, MetadataStorageFormat,
+    MetadataUpdateMode, MintingMode, NFTHolderMode, NFTKind, OrdinalAssignmentMode, OwnershipMode,
+    RevealMode, TokenIdentifier, WhitelistMode,
 };
 
 use super::constants::{NFT_TEST_COLLECTION, NFT_TEST_SYMBOL};
@@ -84,6 +93,8 @@ pub enum NFTMetadataKind {
     NFT721 = 1,
     Raw = 2,
     CustomValidated = 3,
+    BaseURI = 4,
+    Binary = 5,
 }
 
 #[repr(u8)]
@@ -141,7 +152,14 @@ pub(crate) struct InstallerRequestBuilder {
     nft_metadata_kind: CLValue,
     identifier_mode: CLValue,
     metadata_mutability: CLValue,
+    metadata_update_mode: CLValue,
+    metadata_storage_format: CLValue,
+    reveal_mode: CLValue,
+    ordinal_assignment_mode: CLValue,
+    hash_identifier_mode: CLValue,
     burn_mode: CLValue,
+    burn_storage_mode: CLValue,
+    clawback_mode: CLValue,
     operator_burn_mode: CLValue,
     reporting_mode: CLValue,
     named_key_convention: CLValue,
@@ -149,6 +167,22 @@ pub(crate) struct InstallerRequestBuilder {
     optional_metadata: CLValue,
     events_mode: CLValue,
     transfer_filter_contract: Option<CLValue>,
+    locked_metadata_fields: Option<CLValue>,
+    metadata_editor: Option<CLValue>,
+    collection_description: Option<CLValue>,
+    collection_image: Option<CLValue>,
+    base_uri: Option<CLValue>,
+    strict_metadata_validation: Option<CLValue>,
+    allowed_uri_schemes: Option<CLValue>,
+    max_metadata_size: Option<CLValue>,
+    binary_metadata_layout: Option<CLValue>,
+    provenance_hash: Option<CLValue>,
+    placeholder_uri: Option<CLValue>,
+    shuffle_seed: Option<CLValue>,
+    identifier_charset: Option<CLValue>,
+    identifier_min_length: Option<CLValue>,
+    identifier_max_length: Option<CLValue>,
+    identifier_prefix: Option<CLValue>,
 }
 
 impl InstallerRequestBuilder {
@@ -182,7 +216,15 @@ impl InstallerRequestBuilder {
             nft_metadata_kind: CLValue::from_t(NFTMetadataKind::NFT721 as u8).unwrap(),
             identifier_mode: CLValue::from_t(NFTIdentifierMode::Ordinal as u8).unwrap(),
             metadata_mutability: CLValue::from_t(MetadataMutability::Mutable as u8).unwrap(),
+            metadata_update_mode: CLValue::from_t(MetadataUpdateMode::OwnerOnly as u8).unwrap(),
+            metadata_storage_format: CLValue::from_t(MetadataStorageFormat::Pretty as u8).unwrap(),
+            reveal_mode: CLValue::from_t(RevealMode::NoReveal as u8).unwrap(),
+            ordinal_assignment_mode: CLValue::from_t(OrdinalAssignmentMode::Sequential as u8)
+                .unwrap(),
+            hash_identifier_mode: CLValue::from_t(HashIdentifierMode::Derived as u8).unwrap(),
             burn_mode: CLValue::from_t(BurnMode::Burnable as u8).unwrap(),
+            burn_storage_mode: CLValue::from_t(BurnStorageMode::Retain as u8).unwrap(),
+            clawback_mode: CLValue::from_t(ClawbackMode::Disabled as u8).unwrap(),
             operator_burn_mode: CLValue::from_t(false).unwrap(),
             reporting_mode: CLValue::from_t(OwnerReverseLookupMode::Complete as u8).unwrap(),
             named_key_convention: CLValue::from_t(
@@ -193,6 +235,22 @@ impl InstallerRequestBuilder {
             optional_metadata: CLValue::from_t(Bytes::new()).unwrap(),
             events_mode: CLValue::from_t(EventsMode::CES as u8).unwrap(),
             transfer_filter_contract: None,
+            locked_metadata_fields: None,
+            metadata_editor: None,
+            collection_description: None,
+            collection_image: None,
+            base_uri: None,
+            strict_metadata_validation: None,
+            allowed_uri_schemes: None,
+            max_metadata_size: None,
+            binary_metadata_layout: None,
+            provenance_hash: None,
+            placeholder_uri: None,
+            shuffle_seed: None,
+            identifier_charset: None,
+            identifier_min_length: None,
+            identifier_max_length: None,
+            identifier_prefix: None,
         }
     }
 
@@ -326,11 +384,37 @@ impl InstallerRequestBuilder {
         self
     }
 
+    pub(crate) fn with_metadata_update_mode(
+        mut self,
+        metadata_update_mode: MetadataUpdateMode,
+    ) -> Self {
+        self.metadata_update_mode = CLValue::from_t(metadata_update_mode as u8).unwrap();
+        self
+    }
+
+    pub(crate) fn with_metadata_storage_format(
+        mut self,
+        metadata_storage_format: MetadataStorageFormat,
+    ) -> Self {
+        self.metadata_storage_format = CLValue::from_t(metadata_storage_format as u8).unwrap();
+        self
+    }
+
     pub(crate) fn with_burn_mode(mut self, burn_mode: BurnMode) -> Self {
         self.burn_mode = CLValue::from_t(burn_mode as u8).unwrap();
         self
     }
 
+    pub(crate) fn with_burn_storage_mode(mut self, burn_storage_mode: BurnStorageMode) -> Self {
+        self.burn_storage_mode = CLValue::from_t(burn_storage_mode as u8).unwrap();
+        self
+    }
+
+    pub(crate) fn with_clawback_mode(mut self, clawback_mode: ClawbackMode) -> Self {
+        self.clawback_mode = CLValue::from_t(clawback_mode as u8).unwrap();
+        self
+    }
+
     pub(crate) fn with_operator_burn_mode(mut self, operator_burn_mode: bool) -> Self {
         self.operator_burn_mode = CLValue::from_t(operator_burn_mode as u8).unwrap();
         self
@@ -351,6 +435,114 @@ impl InstallerRequestBuilder {
         self
     }
 
+    pub(crate) fn with_locked_metadata_fields(
+        mut self,
+        locked_metadata_fields: Vec<String>,
+    ) -> Self {
+        self.locked_metadata_fields = Some(CLValue::from_t(locked_metadata_fields).unwrap());
+        self
+    }
+
+    pub(crate) fn with_metadata_editor(mut self, metadata_editor: Key) -> Self {
+        self.metadata_editor = Some(CLValue::from_t(Some(metadata_editor)).unwrap());
+        self
+    }
+
+    pub(crate) fn with_collection_description(mut self, collection_description: String) -> Self {
+        self.collection_description = Some(CLValue::from_t(collection_description).unwrap());
+        self
+    }
+
+    pub(crate) fn with_collection_image(mut self, collection_image: String) -> Self {
+        self.collection_image = Some(CLValue::from_t(collection_image).unwrap());
+        self
+    }
+
+    pub(crate) fn with_base_uri(mut self, base_uri: String) -> Self {
+        self.base_uri = Some(CLValue::from_t(base_uri).unwrap());
+        self
+    }
+
+    pub(crate) fn with_strict_metadata_validation(
+        mut self,
+        strict_metadata_validation: bool,
+    ) -> Self {
+        self.strict_metadata_validation =
+            Some(CLValue::from_t(strict_metadata_validation).unwrap());
+        self
+    }
+
+    pub(crate) fn with_allowed_uri_schemes(mut self, allowed_uri_schemes: Vec<String>) -> Self {
+        self.allowed_uri_schemes = Some(CLValue::from_t(allowed_uri_schemes).unwrap());
+        self
+    }
+
+    pub(crate) fn with_max_metadata_size(mut self, max_metadata_size: u64) -> Self {
+        self.max_metadata_size = Some(CLValue::from_t(max_metadata_size).unwrap());
+        self
+    }
+
+    pub(crate) fn with_binary_metadata_layout(mut self, binary_metadata_layout: Vec<u8>) -> Self {
+        self.binary_metadata_layout = Some(CLValue::from_t(binary_metadata_layout).unwrap());
+        self
+    }
+
+    pub(crate) fn with_reveal_mode(mut self, reveal_mode: RevealMode) -> Self {
+        self.reveal_mode = CLValue::from_t(reveal_mode as u8).unwrap();
+        self
+    }
+
+    pub(crate) fn with_provenance_hash(mut self, provenance_hash: String) -> Self {
+        self.provenance_hash = Some(CLValue::from_t(provenance_hash).unwrap());
+        self
+    }
+
+    pub(crate) fn with_placeholder_uri(mut self, placeholder_uri: String) -> Self {
+        self.placeholder_uri = Some(CLValue::from_t(placeholder_uri).unwrap());
+        self
+    }
+
+    pub(crate) fn with_ordinal_assignment_mode(
+        mut self,
+        ordinal_assignment_mode: OrdinalAssignmentMode,
+    ) -> Self {
+        self.ordinal_assignment_mode = CLValue::from_t(ordinal_assignment_mode as u8).unwrap();
+        self
+    }
+
+    pub(crate) fn with_shuffle_seed(mut self, shuffle_seed: String) -> Self {
+        self.shuffle_seed = Some(CLValue::from_t(shuffle_seed).unwrap());
+        self
+    }
+
+    pub(crate) fn with_hash_identifier_mode(
+        mut self,
+        hash_identifier_mode: HashIdentifierMode,
+    ) -> Self {
+        self.hash_identifier_mode = CLValue::from_t(hash_identifier_mode as u8).unwrap();
+        self
+    }
+
+    pub(crate) fn with_identifier_charset(mut self, identifier_charset: String) -> Self {
+        self.identifier_charset = Some(CLValue::from_t(identifier_charset).unwrap());
+        self
+    }
+
+    pub(crate) fn with_identifier_min_length(mut self, identifier_min_length: u32) -> Self {
+        self.identifier_min_length = Some(CLValue::from_t(identifier_min_length).unwrap());
+        self
+    }
+
+    pub(crate) fn with_identifier_max_length(mut self, identifier_max_length: u32) -> Self {
+        self.identifier_max_length = Some(CLValue::from_t(identifier_max_length).unwrap());
+        self
+    }
+
+    pub(crate) fn with_identifier_prefix(mut self, identifier_prefix: String) -> Self {
+        self.identifier_prefix = Some(CLValue::from_t(identifier_prefix).unwrap());
+        self
+    }
+
     pub(crate) fn build(self) -> ExecuteRequest {
         let mut runtime_args = RuntimeArgs::new();
         runtime_args.insert_cl_value(ARG_COLLECTION_NAME, self.collection_name);
@@ -369,7 +561,14 @@ impl InstallerRequestBuilder {
         runtime_args.insert_cl_value(ARG_NFT_METADATA_KIND, self.nft_metadata_kind);
         runtime_args.insert_cl_value(ARG_IDENTIFIER_MODE, self.identifier_mode);
         runtime_args.insert_cl_value(ARG_METADATA_MUTABILITY, self.metadata_mutability);
+        runtime_args.insert_cl_value(ARG_METADATA_UPDATE_MODE, self.metadata_update_mode);
+        runtime_args.insert_cl_value(ARG_METADATA_STORAGE_FORMAT, self.metadata_storage_format);
+        runtime_args.insert_cl_value(ARG_REVEAL_MODE, self.reveal_mode);
+        runtime_args.insert_cl_value(ARG_ORDINAL_ASSIGNMENT_MODE, self.ordinal_assignment_mode);
+        runtime_args.insert_cl_value(ARG_HASH_IDENTIFIER_MODE, self.hash_identifier_mode);
         runtime_args.insert_cl_value(ARG_BURN_MODE, self.burn_mode);
+        runtime_args.insert_cl_value(ARG_BURN_STORAGE_MODE, self.burn_storage_mode);
+        runtime_args.insert_cl_value(ARG_CLAWBACK_MODE, self.clawback_mode);
         runtime_args.insert_cl_value(ARG_OPERATOR_BURN_MODE, self.operator_burn_mode);
         runtime_args.insert_cl_value(ARG_OWNER_LOOKUP_MODE, self.reporting_mode);
         runtime_args.insert_cl_value(ARG_NAMED_KEY_CONVENTION, self.named_key_convention);
@@ -391,6 +590,55 @@ impl InstallerRequestBuilder {
         if let Some(transfer_filter_contract) = self.transfer_filter_contract {
             runtime_args.insert_cl_value(ARG_TRANSFER_FILTER_CONTRACT, transfer_filter_contract);
         }
+        if let Some(locked_metadata_fields) = self.locked_metadata_fields {
+            runtime_args.insert_cl_value(ARG_LOCKED_METADATA_FIELDS, locked_metadata_fields);
+        }
+        if let Some(metadata_editor) = self.metadata_editor {
+            runtime_args.insert_cl_value(ARG_METADATA_EDITOR, metadata_editor);
+        }
+        if let Some(collection_description) = self.collection_description {
+            runtime_args.insert_cl_value(ARG_COLLECTION_DESCRIPTION, collection_description);
+        }
+        if let Some(collection_image) = self.collection_image {
+            runtime_args.insert_cl_value(ARG_COLLECTION_IMAGE, collection_image);
+        }
+        if let Some(base_uri) = self.base_uri {
+            runtime_args.insert_cl_value(ARG_BASE_URI, base_uri);
+        }
+        if let Some(strict_metadata_validation) = self.strict_metadata_validation {
+            runtime_args
+                .insert_cl_value(ARG_STRICT_METADATA_VALIDATION, strict_metadata_validation);
+        }
+        if let Some(allowed_uri_schemes) = self.allowed_uri_schemes {
+            runtime_args.insert_cl_value(ARG_ALLOWED_URI_SCHEMES, allowed_uri_schemes);
+        }
+        if let Some(max_metadata_size) = self.max_metadata_size {
+            runtime_args.insert_cl_value(ARG_MAX_METADATA_SIZE, max_metadata_size);
+        }
+        if let Some(binary_metadata_layout) = self.binary_metadata_layout {
+            runtime_args.insert_cl_value(ARG_BINARY_METADATA_LAYOUT, binary_metadata_layout);
+        }
+        if let Some(provenance_hash) = self.provenance_hash {
+            runtime_args.insert_cl_value(ARG_PROVENANCE_HASH, provenance_hash);
+        }
+        if let Some(placeholder_uri) = self.placeholder_uri {
+            runtime_args.insert_cl_value(ARG_PLACEHOLDER_URI, placeholder_uri);
+        }
+        if let Some(shuffle_seed) = self.shuffle_seed {
+            runtime_args.insert_cl_value(ARG_SHUFFLE_SEED, shuffle_seed);
+        }
+        if let Some(identifier_charset) = self.identifier_charset {
+            runtime_args.insert_cl_value(ARG_IDENTIFIER_CHARSET, identifier_charset);
+        }
+        if let Some(identifier_min_length) = self.identifier_min_length {
+            runtime_args.insert_cl_value(ARG_IDENTIFIER_MIN_LENGTH, identifier_min_length);
+        }
+        if let Some(identifier_max_length) = self.identifier_max_length {
+            runtime_args.insert_cl_value(ARG_IDENTIFIER_MAX_LENGTH, identifier_max_length);
+        }
+        if let Some(identifier_prefix) = self.identifier_prefix {
+            runtime_args.insert_cl_value(ARG_IDENTIFIER_PREFIX, identifier_prefix);
+        }
         ExecuteRequestBuilder::standard(self.account_hash, &self.session_file, runtime_args).build()
     }
 }